@@ -0,0 +1,70 @@
+"""Creates the static Material Symbols instances bundled by the `icons` feature.
+
+Usage: python instance.py <variablefont directory>
+
+The directory is the `variablefont` directory of
+https://github.com/google/material-design-icons. Every combination of the
+fill, grade and optical size axes is instantiated, and the weight axis is kept
+variable. The codepoints are copied to `MaterialSymbols.codepoints`.
+
+Requires fontTools (`pip install fonttools`).
+"""
+
+import shutil
+import sys
+from pathlib import Path
+
+from fontTools.ttLib import TTFont
+from fontTools.varLib import instancer
+
+VARIANTS = ["Outlined", "Rounded", "Sharp"]
+FILLS = [0, 1]
+GRADES = {"N25": -25, "0": 0, "200": 200}
+OPTICAL_SIZES = [20, 24, 40, 48]
+
+NAMES = [1, 3, 4, 6, 16]
+
+
+def main(source: Path, target: Path) -> None:
+    for variant in VARIANTS:
+        path = source / f"MaterialSymbols{variant}[FILL,GRAD,opsz,wght].ttf"
+
+        for fill in FILLS:
+            for grade_name, grade in GRADES.items():
+                for size in OPTICAL_SIZES:
+                    suffix = f"Fill{fill}-Grade{grade_name}-Opsz{size}"
+                    family = (
+                        f"Material Symbols {variant} Fill{fill} "
+                        f"Grade{grade_name} Opsz{size}"
+                    )
+
+                    font = instancer.instantiateVariableFont(
+                        TTFont(path),
+                        {"FILL": fill, "GRAD": grade, "opsz": size},
+                    )
+                    rename(font, family)
+                    font.save(target / f"MaterialSymbols{variant}-{suffix}.ttf")
+
+    shutil.copy(
+        source / "MaterialSymbolsOutlined[FILL,GRAD,opsz,wght].codepoints",
+        target / "MaterialSymbols.codepoints",
+    )
+
+
+def rename(font: TTFont, family: str) -> None:
+    name = font["name"]
+    postscript = family.replace(" ", "")
+
+    for record in list(name.names):
+        if record.nameID in NAMES:
+            name.removeNames(nameID=record.nameID)
+
+    name.setName(family, 1, 3, 1, 0x409)
+    name.setName(f"{postscript}-Regular", 3, 3, 1, 0x409)
+    name.setName(family, 4, 3, 1, 0x409)
+    name.setName(f"{postscript}-Regular", 6, 3, 1, 0x409)
+    name.setName(family, 16, 3, 1, 0x409)
+
+
+if __name__ == "__main__":
+    main(Path(sys.argv[1]), Path(__file__).parent)
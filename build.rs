@@ -0,0 +1,135 @@
+//! Generates the font table and the `Icon` enum of the `icons` feature.
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::{env, fs};
+
+const FONTS: &str = "assets/fonts";
+const CODEPOINTS: &str = "assets/fonts/MaterialSymbols.codepoints";
+
+const VARIANTS: &[&str] = &["Outlined", "Rounded", "Sharp"];
+const FILLS: &[(&str, &str)] = &[("false", "0"), ("true", "1")];
+const GRADES: &[(&str, &str)] =
+    &[("Low", "N25"), ("Normal", "0"), ("High", "200")];
+const OPTICAL_SIZES: &[(&str, &str)] = &[
+    ("Small", "20"),
+    ("Medium", "24"),
+    ("Large", "40"),
+    ("ExtraLarge", "48"),
+];
+
+fn main() {
+    if env::var_os("CARGO_FEATURE_ICONS").is_none() {
+        return;
+    }
+
+    let manifest = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out = env::var("OUT_DIR").unwrap();
+
+    println!("cargo::rerun-if-changed={FONTS}");
+
+    fs::write(Path::new(&out).join("icon_fonts.rs"), fonts(&manifest)).unwrap();
+    fs::write(Path::new(&out).join("icons.rs"), icons(&manifest)).unwrap();
+}
+
+/// Generates [`FONTS`] and the family lookup of every static instance.
+fn fonts(manifest: &str) -> String {
+    let mut bytes = String::new();
+    let mut families = String::new();
+
+    for variant in VARIANTS {
+        for (fill, fill_name) in FILLS {
+            for (grade, grade_name) in GRADES {
+                for (size, size_name) in OPTICAL_SIZES {
+                    let file = format!(
+                        "{manifest}/{FONTS}/MaterialSymbols{variant}-\
+                         Fill{fill_name}-Grade{grade_name}-Opsz{size_name}.ttf"
+                    );
+                    let family = format!(
+                        "Material Symbols {variant} Fill{fill_name} \
+                         Grade{grade_name} Opsz{size_name}"
+                    );
+
+                    writeln!(bytes, "    include_bytes!({file:?}),").unwrap();
+                    writeln!(
+                        families,
+                        "        (Variant::{variant}, {fill}, Grade::{grade}, \
+                         OpticalSize::{size}) => {family:?},"
+                    )
+                    .unwrap();
+                }
+            }
+        }
+    }
+
+    format!(
+        "/// The bytes of every bundled font file.\n\
+         pub const FONTS: &[&[u8]] = &[\n{bytes}];\n\n\
+         const fn family(\n    \
+             variant: Variant,\n    \
+             fill: bool,\n    \
+             grade: Grade,\n    \
+             optical_size: OpticalSize,\n\
+         ) -> &'static str {{\n    \
+             match (variant, fill, grade, optical_size) {{\n\
+                 {families}    \
+             }}\n\
+         }}\n"
+    )
+}
+
+/// Generates the `icons!` invocation from the Material Symbols codepoints.
+fn icons(manifest: &str) -> String {
+    let path = Path::new(manifest).join(CODEPOINTS);
+    println!("cargo::rerun-if-changed={CODEPOINTS}");
+
+    let codepoints = fs::read_to_string(&path).unwrap_or_else(|error| {
+        panic!(
+            "the `icons` feature needs {}: {error}; see assets/fonts/instance.py",
+            path.display()
+        )
+    });
+
+    let mut seen = HashSet::new();
+    let mut icons = String::from("icons! {\n");
+
+    for line in codepoints.lines() {
+        let Some((name, codepoint)) = line.trim().split_once(' ') else {
+            continue;
+        };
+
+        let variant = variant(name);
+
+        if seen.insert(variant.clone()) {
+            writeln!(icons, "    {variant} => {name:?}, '\\u{{{codepoint}}}',")
+                .unwrap();
+        }
+    }
+
+    icons.push_str("}\n");
+    icons
+}
+
+/// Turns a snake case icon name into an enum variant.
+fn variant(name: &str) -> String {
+    let mut variant = String::new();
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        variant.push_str("Num");
+    }
+
+    for part in name.split('_') {
+        let mut chars = part.chars();
+
+        if let Some(first) = chars.next() {
+            variant.push(first.to_ascii_uppercase());
+            variant.extend(chars);
+        }
+    }
+
+    if variant == "Self" {
+        variant.push_str("Icon");
+    }
+
+    variant
+}
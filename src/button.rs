@@ -1,24 +1,51 @@
 use iced_widget::button::{Catalog, Status, Style, StyleFn};
 use iced_widget::core::{Background, Border, Color, border};
 
-use crate::Theme;
-use crate::utils::{
-    HOVERED_LAYER_OPACITY, PRESSED_LAYER_OPACITY, disabled_container,
-    disabled_text, elevation, mix, shadow_from_elevation,
-};
+use crate::class::style_class;
+use crate::overrides::Component;
+use crate::shape::Shape;
+use crate::utils::State;
+use crate::{Role, Theme};
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(filled)
+        Class::Filled
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.style(self, status)
     }
 }
 
+style_class! {
+    /// The named styles of a button.
+    (status: Status) -> Style {
+        Elevated => elevated,
+        Filled => filled,
+        FilledTonal => filled_tonal,
+        Outlined => outlined,
+        Text => text,
+    }
+}
+
+impl From<Status> for State {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Active => Self::Enabled,
+            Status::Hovered => Self::Hovered,
+            Status::Pressed => Self::Pressed,
+            Status::Disabled => Self::Disabled,
+        }
+    }
+}
+
+/// A button style using the default shape, elevation and state layer tokens.
+///
+/// The tokens of the active [`Theme`] are ignored, so custom shapes and state
+/// layers don't apply.
+#[deprecated(note = "use `themed`, which takes the `Theme` to style with")]
 pub fn styled(
     background: Color,
     foreground: Color,
@@ -27,42 +54,47 @@ pub fn styled(
     elevation_level: u8,
     status: Status,
 ) -> Style {
-    let active = Style {
-        background: Some(Background::Color(background)),
-        text_color: foreground,
-        border: border::rounded(400),
-        shadow: shadow_from_elevation(elevation(elevation_level), shadow_color),
-        snap: cfg!(feature = "crisp"),
+    themed(
+        &Theme::Light,
+        background,
+        foreground,
+        disabled,
+        shadow_color,
+        elevation_level,
+        status,
+    )
+}
+
+/// A button style using the shape, elevation and state layer tokens of the
+/// given [`Theme`].
+pub fn themed(
+    theme: &Theme,
+    background: Color,
+    foreground: Color,
+    disabled: Color,
+    shadow_color: Color,
+    elevation_level: u8,
+    status: Status,
+) -> Style {
+    let (background, text_color) = theme.state_layer().compose(
+        background,
+        foreground,
+        disabled,
+        status.into(),
+    );
+
+    let elevation_level = match status {
+        Status::Active | Status::Pressed => elevation_level,
+        Status::Hovered => elevation_level.saturating_add(1),
+        Status::Disabled => 0,
     };
 
-    match status {
-        Status::Active => active,
-        Status::Pressed => Style {
-            background: Some(Background::Color(mix(
-                background,
-                foreground,
-                HOVERED_LAYER_OPACITY,
-            ))),
-            ..active
-        },
-        Status::Hovered => Style {
-            background: Some(Background::Color(mix(
-                background,
-                foreground,
-                PRESSED_LAYER_OPACITY,
-            ))),
-            shadow: shadow_from_elevation(
-                elevation(elevation_level + 1),
-                shadow_color,
-            ),
-            ..active
-        },
-        Status::Disabled => Style {
-            background: Some(Background::Color(disabled_container(disabled))),
-            text_color: disabled_text(disabled),
-            border: border::rounded(400),
-            ..Default::default()
-        },
+    Style {
+        background: Some(Background::Color(background)),
+        text_color,
+        border: border::rounded(theme.shapes().full),
+        shadow: theme.elevation().shadow(elevation_level, shadow_color),
+        snap: cfg!(feature = "crisp"),
     }
 }
 
@@ -75,19 +107,42 @@ pub fn elevated(theme: &Theme, status: Status) -> Style {
 
     let shadow_color = theme.colors().shadow;
 
-    styled(background, foreground, disabled, shadow_color, 1, status)
+    themed(
+        theme,
+        background,
+        foreground,
+        disabled,
+        shadow_color,
+        1,
+        status,
+    )
 }
 
 pub fn filled(theme: &Theme, status: Status) -> Style {
-    let primary = theme.colors().primary;
+    let overrides = theme.component_override(Component::Button, status.into());
+    let group = theme.colors().role(overrides.role_or(Role::Primary));
 
-    let foreground = primary.on_primary;
-    let background = primary.color;
+    let foreground = group.on_color;
+    let background = group.color;
     let disabled = theme.colors().surface.on_surface;
 
     let shadow_color = theme.colors().shadow;
 
-    styled(background, foreground, disabled, shadow_color, 0, status)
+    let style = themed(
+        theme,
+        background,
+        foreground,
+        disabled,
+        shadow_color,
+        0,
+        status,
+    );
+
+    Style {
+        border: overrides.border(style.border),
+        shadow: overrides.shadow(theme, style.shadow),
+        ..style
+    }
 }
 
 pub fn filled_tonal(theme: &Theme, status: Status) -> Style {
@@ -98,7 +153,15 @@ pub fn filled_tonal(theme: &Theme, status: Status) -> Style {
     let disabled = theme.colors().surface.on_surface;
     let shadow_color = theme.colors().shadow;
 
-    styled(background, foreground, disabled, shadow_color, 0, status)
+    themed(
+        theme,
+        background,
+        foreground,
+        disabled,
+        shadow_color,
+        0,
+        status,
+    )
 }
 
 pub fn outlined(theme: &Theme, status: Status) -> Style {
@@ -112,16 +175,17 @@ pub fn outlined(theme: &Theme, status: Status) -> Style {
         Status::Active | Status::Pressed | Status::Hovered => Border {
             color: outline,
             width: 1.0,
-            radius: 400.into(),
+            radius: theme.shapes().full.into(),
         },
         Status::Disabled => Border {
-            color: disabled_container(disabled),
+            color: theme.state_layer().disabled_container(disabled),
             width: 1.0,
-            radius: 400.into(),
+            radius: theme.shapes().full.into(),
         },
     };
 
-    let style = styled(
+    let style = themed(
+        theme,
         background,
         foreground,
         disabled,
@@ -138,7 +202,8 @@ pub fn text(theme: &Theme, status: Status) -> Style {
     let background = Color::TRANSPARENT;
     let disabled = theme.colors().surface.on_surface;
 
-    let style = styled(
+    let style = themed(
+        theme,
         background,
         foreground,
         disabled,
@@ -155,3 +220,164 @@ pub fn text(theme: &Theme, status: Status) -> Style {
         },
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Elevated,
+    Filled,
+    Tonal,
+    Outlined,
+    Text,
+}
+
+/// A builder for button styles.
+///
+/// The resulting style applies the [`Theme`]'s state layers, so it looks
+/// right in every [`Status`].
+///
+/// # Example
+///
+/// ```no_run
+/// use iced::widget::{Button, button};
+/// use iced_material::button::ButtonStyle;
+/// use iced_material::shape::Shape;
+/// use iced_material::{Role, Theme};
+///
+/// let style = ButtonStyle::tonal()
+///     .role(Role::Tertiary)
+///     .shape(Shape::Medium)
+///     .elevation(1);
+///
+/// let save: Button<'_, (), Theme> = button("Save").style(style.into_fn());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ButtonStyle {
+    kind: Kind,
+    role: Role,
+    shape: Shape,
+    elevation: u8,
+}
+
+impl ButtonStyle {
+    const fn new(kind: Kind, role: Role, elevation: u8) -> Self {
+        Self {
+            kind,
+            role,
+            shape: Shape::Full,
+            elevation,
+        }
+    }
+
+    pub const fn elevated() -> Self {
+        Self::new(Kind::Elevated, Role::Primary, 1)
+    }
+
+    pub const fn filled() -> Self {
+        Self::new(Kind::Filled, Role::Primary, 0)
+    }
+
+    /// A tonal button, using the secondary [`Role`] unless told otherwise.
+    pub const fn tonal() -> Self {
+        Self::new(Kind::Tonal, Role::Secondary, 0)
+    }
+
+    pub const fn outlined() -> Self {
+        Self::new(Kind::Outlined, Role::Primary, 0)
+    }
+
+    pub const fn text() -> Self {
+        Self::new(Kind::Text, Role::Primary, 0)
+    }
+
+    /// Sets the color [`Role`] of the button.
+    pub const fn role(self, role: Role) -> Self {
+        Self { role, ..self }
+    }
+
+    /// Sets the [`Shape`] of the button.
+    pub const fn shape(self, shape: Shape) -> Self {
+        Self { shape, ..self }
+    }
+
+    /// Sets the elevation level of the button at rest.
+    pub const fn elevation(self, elevation: u8) -> Self {
+        Self { elevation, ..self }
+    }
+
+    /// Turns the style into a function that can be passed to the `style`
+    /// method of a [`Button`].
+    ///
+    /// [`Button`]: iced_widget::Button
+    pub fn into_fn(self) -> impl Fn(&Theme, Status) -> Style + Copy {
+        move |theme, status| self.style(theme, status)
+    }
+
+    /// Computes the [`Style`] of the button.
+    pub fn style(&self, theme: &Theme, status: Status) -> Style {
+        let colors = theme.colors();
+        let group = colors.role(self.role);
+        let disabled = colors.surface.on_surface;
+
+        let (background, foreground, shadow_color) = match self.kind {
+            Kind::Elevated => (
+                colors.surface.surface_container.low,
+                group.color,
+                colors.shadow,
+            ),
+            Kind::Filled => (group.color, group.on_color, colors.shadow),
+            Kind::Tonal => (group.container, group.on_container, colors.shadow),
+            Kind::Outlined | Kind::Text => {
+                (Color::TRANSPARENT, group.color, Color::TRANSPARENT)
+            }
+        };
+
+        let style = themed(
+            theme,
+            background,
+            foreground,
+            disabled,
+            shadow_color,
+            self.elevation,
+            status,
+        );
+
+        let radius = theme.shapes().radius(self.shape);
+
+        let border = if self.kind == Kind::Outlined {
+            Border {
+                color: if status == Status::Disabled {
+                    theme.state_layer().disabled_container(disabled)
+                } else {
+                    colors.outline.color
+                },
+                width: 1.0,
+                radius: radius.into(),
+            }
+        } else {
+            border::rounded(radius)
+        };
+
+        let background = match (self.kind, status) {
+            (Kind::Text, Status::Active | Status::Disabled) => None,
+            _ => style.background,
+        };
+
+        Style {
+            background,
+            border,
+            ..style
+        }
+    }
+}
+
+impl From<ButtonStyle> for StyleFn<'_, Theme> {
+    fn from(style: ButtonStyle) -> Self {
+        Box::new(move |theme, status| style.style(theme, status))
+    }
+}
+
+impl From<ButtonStyle> for Class<'_> {
+    fn from(style: ButtonStyle) -> Self {
+        Self::Custom(style.into())
+    }
+}
@@ -0,0 +1,495 @@
+//! [Button groups](https://m3.material.io/components/button-groups/overview).
+//!
+//! A [`ButtonGroup`] lays out a row of buttons. In a standard group, a
+//! pressed button widens while its neighbors shrink to make room for it. A
+//! connected group joins its buttons with a small gap instead.
+//!
+//! The buttons themselves are regular `iced` buttons. The [`toggle`] and
+//! [`connected_with`] helpers morph the shape of any button style between
+//! round and square depending on the selection.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced_material::button_group::{self, ButtonGroup};
+//! use iced_material::{Theme, button};
+//! use iced_widget::text;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum Align {
+//!     Left,
+//!     Center,
+//!     Right,
+//! }
+//!
+//! # fn view<'a>(align: Align) -> iced_widget::core::Element<'a, Align, Theme, iced_widget::Renderer> {
+//! let options = [Align::Left, Align::Center, Align::Right];
+//!
+//! ButtonGroup::connected(options.iter().enumerate().map(|(index, option)| {
+//!     iced_widget::button(text(format!("{option:?}")))
+//!         .on_press(*option)
+//!         .style(button_group::connected_with(
+//!             button::filled_tonal,
+//!             index,
+//!             options.len(),
+//!             *option == align,
+//!         ))
+//!         .into()
+//! }))
+//! .into()
+//! # }
+//! ```
+use std::fmt;
+
+use iced_widget::button::{Status, Style};
+use iced_widget::core::border::Radius;
+use iced_widget::core::layout::{self, Layout, Limits};
+use iced_widget::core::time::Instant;
+use iced_widget::core::widget::{Operation, Tree, tree};
+use iced_widget::core::{
+    self, Clipboard, Element, Event, Length, Point, Rectangle, Shell, Size,
+    Vector, Widget, mouse, overlay, renderer, touch, window,
+};
+
+use crate::Theme;
+use crate::motion::{duration, easing};
+
+/// The fraction of its width a pressed button of a standard group grows by.
+const WIDEN: f32 = 0.15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Standard,
+    Connected,
+}
+
+/// A row of buttons.
+pub struct ButtonGroup<'a, Message, Renderer = iced_widget::Renderer> {
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    kind: Kind,
+    spacing: f32,
+}
+
+impl<'a, Message, Renderer> ButtonGroup<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// Creates a standard [`ButtonGroup`], where pressed buttons widen.
+    pub fn new(
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            children: children.into_iter().collect(),
+            kind: Kind::Standard,
+            spacing: 12.0,
+        }
+    }
+
+    /// Creates a connected [`ButtonGroup`].
+    ///
+    /// Its buttons are usually styled with [`connected_with`].
+    pub fn connected(
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            kind: Kind::Connected,
+            spacing: 2.0,
+            ..Self::new(children)
+        }
+    }
+
+    /// Sets the spacing between the buttons.
+    pub fn spacing(self, spacing: f32) -> Self {
+        Self { spacing, ..self }
+    }
+
+    /// Adds a button to the [`ButtonGroup`].
+    pub fn push(
+        mut self,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<Message, Renderer> fmt::Debug for ButtonGroup<'_, Message, Renderer> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ButtonGroup")
+            .field("kind", &self.kind)
+            .field("spacing", &self.spacing)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The widening animation of a standard group.
+#[derive(Debug, Default)]
+struct State {
+    pressed: Option<usize>,
+    widened: Option<usize>,
+    progress: f32,
+    last_tick: Option<Instant>,
+}
+
+impl State {
+    fn target(&self) -> f32 {
+        if self.pressed.is_some() { 1.0 } else { 0.0 }
+    }
+
+    fn is_animating(&self) -> bool {
+        self.progress != self.target()
+    }
+
+    fn press(&mut self, index: usize) {
+        if self.widened != Some(index) {
+            self.widened = Some(index);
+            self.progress = 0.0;
+        }
+
+        self.pressed = Some(index);
+    }
+
+    fn tick(&mut self, now: Instant) {
+        let elapsed = self
+            .last_tick
+            .map_or(0.0, |last| (now - last).as_secs_f32());
+        let step = elapsed / duration::SHORT3.as_secs_f32();
+
+        self.progress = if self.target() > self.progress {
+            (self.progress + step).min(1.0)
+        } else {
+            (self.progress - step).max(0.0)
+        };
+        self.last_tick = self.is_animating().then_some(now);
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer>
+    for ButtonGroup<'_, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_ref::<State>();
+        let widened = state.widened.filter(|_| self.kind == Kind::Standard);
+        let factor = easing::STANDARD.ease(state.progress);
+
+        let limits = limits.loose();
+        let mut nodes: Vec<_> = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(child, tree)| {
+                child.as_widget_mut().layout(tree, renderer, &limits)
+            })
+            .collect();
+
+        if let Some(index) =
+            widened.filter(|index| factor > 0.0 && *index < nodes.len())
+        {
+            let neighbors: Vec<_> = [index.checked_sub(1), Some(index + 1)]
+                .into_iter()
+                .flatten()
+                .filter(|neighbor| *neighbor < nodes.len())
+                .collect();
+
+            if !neighbors.is_empty() {
+                let extra = nodes[index].size().width * WIDEN * factor;
+                let shrink = extra / neighbors.len() as f32;
+
+                let widths: Vec<_> = std::iter::once((index, extra))
+                    .chain(neighbors.into_iter().map(|n| (n, -shrink)))
+                    .map(|(index, delta)| {
+                        (index, nodes[index].size().width + delta)
+                    })
+                    .collect();
+
+                for (index, width) in widths {
+                    let width = width.max(0.0);
+
+                    nodes[index] = self.children[index].as_widget_mut().layout(
+                        &mut tree.children[index],
+                        renderer,
+                        &Limits::new(
+                            Size::new(width, 0.0),
+                            Size::new(width, limits.max().height),
+                        ),
+                    );
+                }
+            }
+        }
+
+        let height = nodes
+            .iter()
+            .map(|node| node.size().height)
+            .fold(0.0, f32::max);
+
+        let mut x = 0.0;
+
+        for node in &mut nodes {
+            let size = node.size();
+
+            node.move_to_mut(Point::new(x, (height - size.height) / 2.0));
+            x += size.width + self.spacing;
+        }
+
+        let width = (x - self.spacing).max(0.0);
+
+        layout::Node::with_children(
+            limits.resolve(
+                Length::Shrink,
+                Length::Shrink,
+                Size::new(width, height),
+            ),
+            nodes,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if self.kind == Kind::Standard {
+            let state = tree.state.downcast_mut::<State>();
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    if let Some(index) = layout
+                        .children()
+                        .position(|child| cursor.is_over(child.bounds()))
+                    {
+                        state.press(index);
+                        shell.request_redraw();
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. }
+                    | touch::Event::FingerLost { .. },
+                ) if state.pressed.take().is_some() => {
+                    shell.request_redraw();
+                }
+                Event::Window(window::Event::RedrawRequested(now))
+                    if state.is_animating() =>
+                {
+                    state.tick(*now);
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+                _ => {}
+            }
+        }
+
+        for ((child, tree), layout) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            child.as_widget_mut().update(
+                tree, event, layout, cursor, renderer, clipboard, shell,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, tree), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.children,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<ButtonGroup<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(group: ButtonGroup<'a, Message, Renderer>) -> Self {
+        Self::new(group)
+    }
+}
+
+/// Morphs the shape of the given button style for a toggle button of a
+/// standard group.
+///
+/// The button is round when unselected and square when selected. Pressing
+/// it makes its corners even squarer.
+pub fn toggle<'a>(
+    style: impl Fn(&Theme, Status) -> Style + Copy + 'a,
+    selected: bool,
+) -> impl Fn(&Theme, Status) -> Style + Copy + 'a {
+    move |theme, status| {
+        let shapes = theme.shapes();
+
+        let radius = match status {
+            Status::Pressed => shapes.small,
+            _ if selected => shapes.medium,
+            _ => shapes.full,
+        };
+
+        with_radius(style(theme, status), radius.into())
+    }
+}
+
+/// Morphs the shape of the given button style for the button at `index` of
+/// a connected group of `len` buttons.
+///
+/// The outer corners of the group are round and the inner ones are square.
+/// A selected button becomes fully round.
+pub fn connected_with<'a>(
+    style: impl Fn(&Theme, Status) -> Style + Copy + 'a,
+    index: usize,
+    len: usize,
+    selected: bool,
+) -> impl Fn(&Theme, Status) -> Style + Copy + 'a {
+    move |theme, status| {
+        let shapes = theme.shapes();
+
+        let inner = if status == Status::Pressed {
+            shapes.extra_small
+        } else {
+            shapes.small
+        };
+
+        let (left, right) = if selected {
+            (shapes.full, shapes.full)
+        } else {
+            (
+                if index == 0 { shapes.full } else { inner },
+                if index + 1 == len { shapes.full } else { inner },
+            )
+        };
+
+        with_radius(
+            style(theme, status),
+            Radius {
+                top_left: left,
+                top_right: right,
+                bottom_right: right,
+                bottom_left: left,
+            },
+        )
+    }
+}
+
+fn with_radius(style: Style, radius: Radius) -> Style {
+    Style {
+        border: style.border.rounded(radius),
+        ..style
+    }
+}
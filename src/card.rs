@@ -0,0 +1,253 @@
+//! [Cards](https://m3.material.io/components/cards/overview).
+//!
+//! A [`Card`] is a [`Container`] with the shape, colors and elevation of one
+//! of the three M3 card [`Kind`]s. Giving it an `on_press` message turns it
+//! into a button with hover, pressed and disabled states.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced_material::Theme;
+//! use iced_material::card::{self, Kind};
+//! use iced_widget::{column, text};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     OpenArticle,
+//! }
+//!
+//! # fn view<'a>() -> iced_widget::core::Element<'a, Message, Theme, iced_widget::Renderer> {
+//! column![
+//!     card::card(Kind::Outlined, text("Static content")),
+//!     card::card(Kind::Elevated, text("Read more"))
+//!         .on_press(Message::OpenArticle),
+//! ]
+//! .into()
+//! # }
+//! ```
+use std::fmt;
+
+use iced_widget::core::{
+    self, Background, Border, Element, Length, Padding, border,
+};
+use iced_widget::{Button, Container, button, container};
+
+use crate::Theme;
+use crate::utils::State;
+
+/// The kind of a card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Kind {
+    #[default]
+    Elevated,
+    Filled,
+    Outlined,
+}
+
+impl Kind {
+    /// The elevation level of a card of this [`Kind`] in the given
+    /// [`State`].
+    pub fn elevation(self, state: State) -> u8 {
+        let base = match self {
+            Self::Elevated => 1,
+            Self::Filled | Self::Outlined => 0,
+        };
+
+        match state {
+            State::Hovered => base + 1,
+            State::Dragged => base + 3,
+            State::Enabled
+            | State::Focused
+            | State::Pressed
+            | State::Disabled => base,
+        }
+    }
+}
+
+/// A card.
+pub struct Card<'a, Message, Renderer = iced_widget::Renderer> {
+    kind: Kind,
+    content: Element<'a, Message, Theme, Renderer>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    is_interactive: bool,
+    is_dragged: bool,
+    on_press: Option<Message>,
+}
+
+/// Creates a [`Card`] of the given [`Kind`].
+pub fn card<'a, Message, Renderer>(
+    kind: Kind,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Card<'a, Message, Renderer> {
+    Card {
+        kind,
+        content: content.into(),
+        width: Length::Shrink,
+        height: Length::Shrink,
+        padding: Padding::new(16.0),
+        is_interactive: false,
+        is_dragged: false,
+        on_press: None,
+    }
+}
+
+impl<Message, Renderer> Card<'_, Message, Renderer> {
+    /// Sets the width of the [`Card`].
+    pub fn width(self, width: impl Into<Length>) -> Self {
+        Self {
+            width: width.into(),
+            ..self
+        }
+    }
+
+    /// Sets the height of the [`Card`].
+    pub fn height(self, height: impl Into<Length>) -> Self {
+        Self {
+            height: height.into(),
+            ..self
+        }
+    }
+
+    /// Sets the [`Padding`] of the [`Card`].
+    pub fn padding(self, padding: impl Into<Padding>) -> Self {
+        Self {
+            padding: padding.into(),
+            ..self
+        }
+    }
+
+    /// Makes the [`Card`] interactive, producing the given message when
+    /// pressed.
+    pub fn on_press(self, on_press: Message) -> Self {
+        self.on_press_maybe(Some(on_press))
+    }
+
+    /// Makes the [`Card`] interactive, producing the given message when
+    /// pressed. The [`Card`] is disabled if there is no message.
+    pub fn on_press_maybe(self, on_press: Option<Message>) -> Self {
+        Self {
+            on_press,
+            is_interactive: true,
+            ..self
+        }
+    }
+
+    /// Sets whether the [`Card`] is being dragged, raising it to the drag
+    /// elevation.
+    pub fn dragged(self, is_dragged: bool) -> Self {
+        Self { is_dragged, ..self }
+    }
+}
+
+impl<Message, Renderer> fmt::Debug for Card<'_, Message, Renderer> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Card")
+            .field("kind", &self.kind)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("padding", &self.padding)
+            .field("is_interactive", &self.is_interactive)
+            .field("is_dragged", &self.is_dragged)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Message, Renderer> From<Card<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(card: Card<'a, Message, Renderer>) -> Self {
+        let kind = card.kind;
+
+        if card.is_interactive {
+            let is_dragged = card.is_dragged;
+
+            Button::new(card.content)
+                .width(card.width)
+                .height(card.height)
+                .padding(card.padding)
+                .on_press_maybe(card.on_press)
+                .style(move |theme, status| {
+                    let state =
+                        if is_dragged && status != button::Status::Disabled {
+                            State::Dragged
+                        } else {
+                            status.into()
+                        };
+
+                    let style = styled(theme, kind, state);
+
+                    button::Style {
+                        background: style.background,
+                        text_color: style.text_color.unwrap_or_default(),
+                        border: style.border,
+                        shadow: style.shadow,
+                        snap: style.snap,
+                    }
+                })
+                .into()
+        } else {
+            let state = if card.is_dragged {
+                State::Dragged
+            } else {
+                State::Enabled
+            };
+
+            Container::new(card.content)
+                .width(card.width)
+                .height(card.height)
+                .padding(card.padding)
+                .style(move |theme| styled(theme, kind, state))
+                .into()
+        }
+    }
+}
+
+/// A card of the given [`Kind`] in the given [`State`].
+pub fn styled(theme: &Theme, kind: Kind, state: State) -> container::Style {
+    let colors = theme.colors();
+    let surface = colors.surface;
+
+    let background = match kind {
+        Kind::Elevated => surface.surface_container.low,
+        Kind::Filled => surface.surface_container.highest,
+        Kind::Outlined => surface.color,
+    };
+
+    let (background, text_color) = theme.state_layer().compose(
+        background,
+        surface.on_surface,
+        surface.on_surface,
+        state,
+    );
+
+    let radius = theme.shapes().medium;
+
+    let border = match kind {
+        Kind::Outlined => Border {
+            color: if state == State::Disabled {
+                theme.state_layer().disabled_container(surface.on_surface)
+            } else {
+                colors.outline.variant
+            },
+            width: 1.0,
+            radius: radius.into(),
+        },
+        Kind::Elevated | Kind::Filled => border::rounded(radius),
+    };
+
+    container::Style {
+        background: Some(Background::Color(background)),
+        text_color: Some(text_color),
+        border,
+        shadow: theme
+            .elevation()
+            .shadow(kind.elevation(state), colors.shadow),
+        snap: cfg!(feature = "crisp"),
+    }
+}
@@ -1,18 +1,38 @@
 use iced_widget::checkbox::{Catalog, Status, Style, StyleFn};
 use iced_widget::core::{Background, Border, Color, border};
 
-use super::Theme;
-use crate::utils::{HOVERED_LAYER_OPACITY, disabled_text, mix};
+use super::{Role, Theme};
+use crate::class::style_class;
+use crate::overrides::Component;
+use crate::utils::State;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        Class::Default
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.style(self, status)
+    }
+}
+
+style_class! {
+    /// The named styles of a checkbox.
+    (status: Status) -> Style {
+        Default => default,
+        Error => error,
+    }
+}
+
+impl From<Status> for State {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Active { .. } => Self::Enabled,
+            Status::Hovered { .. } => Self::Hovered,
+            Status::Disabled { .. } => Self::Disabled,
+        }
     }
 }
 
@@ -45,71 +65,96 @@ pub fn styled(
 }
 
 pub fn default(theme: &Theme, status: Status) -> Style {
-    let surface = theme.colors().surface;
-    let primary = theme.colors().primary;
+    let overrides =
+        theme.component_override(Component::Checkbox, status.into());
+    let style = styled_with(overrides.role_or(Role::Primary))(theme, status);
 
-    match status {
-        Status::Active { is_checked } => styled(
-            primary.color,
-            None,
-            primary.on_primary,
-            surface.on_surface_variant,
-            Some(surface.on_surface),
-            is_checked,
-        ),
-        Status::Hovered { is_checked } => styled(
-            mix(primary.color, surface.on_surface, HOVERED_LAYER_OPACITY),
-            Some(Color {
-                a: HOVERED_LAYER_OPACITY,
-                ..surface.on_surface
-            }),
-            primary.on_primary,
-            surface.on_surface_variant,
-            Some(surface.on_surface),
-            is_checked,
-        ),
-        Status::Disabled { is_checked } => styled(
-            disabled_text(surface.on_surface),
-            None,
-            surface.color,
-            disabled_text(surface.on_surface),
-            Some(surface.on_surface),
-            is_checked,
-        ),
+    Style {
+        border: overrides.border(style.border),
+        ..style
     }
 }
 
 pub fn error(theme: &Theme, status: Status) -> Style {
-    let surface = theme.colors().surface;
     let error = theme.colors().error;
 
+    colored(
+        theme,
+        error.color,
+        error.on_error,
+        error.color,
+        error.color,
+        error.color,
+        status,
+    )
+}
+
+pub fn styled_with(role: Role) -> impl Fn(&Theme, Status) -> Style {
+    move |theme, status| {
+        let surface = theme.colors().surface;
+        let group = theme.colors().role(role);
+
+        colored(
+            theme,
+            group.color,
+            group.on_color,
+            surface.on_surface,
+            surface.on_surface_variant,
+            surface.on_surface,
+            status,
+        )
+    }
+}
+
+fn colored(
+    theme: &Theme,
+    checked: Color,
+    icon: Color,
+    layer: Color,
+    outline: Color,
+    text: Color,
+    status: Status,
+) -> Style {
+    let surface = theme.colors().surface;
+    let state_layer = theme.state_layer();
+
     match status {
-        Status::Active { is_checked } => styled(
-            error.color,
-            None,
-            error.on_error,
-            error.color,
-            Some(error.color),
-            is_checked,
-        ),
-        Status::Hovered { is_checked } => styled(
-            mix(error.color, surface.on_surface, HOVERED_LAYER_OPACITY),
-            Some(Color {
-                a: HOVERED_LAYER_OPACITY,
-                ..error.color
-            }),
-            error.on_error,
-            error.color,
-            Some(error.color),
-            is_checked,
-        ),
-        Status::Disabled { is_checked } => styled(
-            disabled_text(surface.on_surface),
-            None,
-            surface.color,
-            disabled_text(surface.on_surface),
-            Some(surface.on_surface),
-            is_checked,
-        ),
+        Status::Active { is_checked } | Status::Hovered { is_checked } => {
+            let state = State::from(status);
+
+            let (background, _) = state_layer.compose(
+                checked,
+                surface.on_surface,
+                surface.on_surface,
+                state,
+            );
+            let (unchecked, _) = state_layer.compose(
+                Color::TRANSPARENT,
+                layer,
+                surface.on_surface,
+                state,
+            );
+
+            styled(
+                background,
+                Some(unchecked),
+                icon,
+                outline,
+                Some(text),
+                is_checked,
+            )
+        }
+        Status::Disabled { is_checked } => {
+            let disabled = state_layer.disabled_content(surface.on_surface);
+
+            styled(
+                disabled,
+                None,
+                surface.color,
+                disabled,
+                Some(surface.on_surface),
+                is_checked,
+            )
+        }
     }
 }
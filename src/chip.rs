@@ -0,0 +1,376 @@
+//! [Chips](https://m3.material.io/components/chips/overview).
+//!
+//! There are four kinds of chips:
+//!
+//! - [`assist`] chips start an action related to the content, like adding an
+//!   event to a calendar.
+//! - [`filter`] chips narrow down content and show a checkmark while
+//!   selected.
+//! - [`input`] chips represent a piece of information entered by the user,
+//!   like a contact, and can be removed.
+//! - [`suggestion`] chips offer dynamically generated suggestions.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced_material::Theme;
+//! use iced_material::chip;
+//! use iced_widget::{row, text};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     ToggleUnread(bool),
+//!     OpenContact,
+//!     RemoveContact,
+//! }
+//!
+//! # fn view<'a>(unread: bool) -> iced_widget::core::Element<'a, Message, Theme, iced_widget::Renderer> {
+//! row![
+//!     chip::filter(text("Unread"), unread)
+//!         .on_press(Message::ToggleUnread(!unread)),
+//!     chip::input(text("Ada Lovelace"))
+//!         .avatar(text("A"))
+//!         .on_press(Message::OpenContact)
+//!         .on_remove(Message::RemoveContact),
+//! ]
+//! .spacing(8)
+//! .into()
+//! # }
+//! ```
+use std::fmt;
+
+use iced_widget::button::{Status, Style};
+use iced_widget::core::text::LineHeight;
+use iced_widget::core::{
+    self, Alignment, Border, Color, Element, Font, Length, Padding,
+};
+use iced_widget::{Button, Container, Row, Text};
+
+use crate::{Theme, button};
+
+/// The height of a chip, in logical pixels.
+pub const HEIGHT: f32 = 32.0;
+
+/// The size of a chip's leading icon, in logical pixels.
+pub const ICON_SIZE: f32 = 18.0;
+
+/// The size of an input chip's avatar, in logical pixels.
+pub const AVATAR_SIZE: f32 = 24.0;
+
+/// The kind of a chip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Kind {
+    Assist,
+    Filter,
+    Input,
+    Suggestion,
+}
+
+/// A chip.
+pub struct Chip<'a, Message, Renderer = iced_widget::Renderer> {
+    kind: Kind,
+    label: Element<'a, Message, Theme, Renderer>,
+    leading: Option<Element<'a, Message, Theme, Renderer>>,
+    is_avatar: bool,
+    selected: bool,
+    elevated: bool,
+    on_press: Option<Message>,
+    on_remove: Option<Message>,
+}
+
+/// Creates an assist chip.
+pub fn assist<'a, Message, Renderer>(
+    label: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Chip<'a, Message, Renderer> {
+    Chip::new(Kind::Assist, label)
+}
+
+/// Creates a filter chip, showing a checkmark when selected.
+pub fn filter<'a, Message, Renderer>(
+    label: impl Into<Element<'a, Message, Theme, Renderer>>,
+    selected: bool,
+) -> Chip<'a, Message, Renderer> {
+    Chip::new(Kind::Filter, label).selected(selected)
+}
+
+/// Creates an input chip.
+///
+/// Use [`Chip::on_remove`] to show its trailing remove icon.
+pub fn input<'a, Message, Renderer>(
+    label: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Chip<'a, Message, Renderer> {
+    Chip::new(Kind::Input, label)
+}
+
+/// Creates a suggestion chip.
+pub fn suggestion<'a, Message, Renderer>(
+    label: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Chip<'a, Message, Renderer> {
+    Chip::new(Kind::Suggestion, label)
+}
+
+impl<'a, Message, Renderer> Chip<'a, Message, Renderer> {
+    /// Creates a [`Chip`] of the given [`Kind`].
+    pub fn new(
+        kind: Kind,
+        label: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            kind,
+            label: label.into(),
+            leading: None,
+            is_avatar: false,
+            selected: false,
+            elevated: false,
+            on_press: None,
+            on_remove: None,
+        }
+    }
+
+    /// Sets the leading icon of the [`Chip`].
+    ///
+    /// A selected filter chip shows a checkmark instead.
+    pub fn icon(
+        self,
+        icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            leading: Some(icon.into()),
+            is_avatar: false,
+            ..self
+        }
+    }
+
+    /// Sets the leading avatar of the [`Chip`], usually an image of a
+    /// person.
+    pub fn avatar(
+        self,
+        avatar: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            leading: Some(avatar.into()),
+            is_avatar: true,
+            ..self
+        }
+    }
+
+    /// Sets whether the [`Chip`] is selected.
+    ///
+    /// Assist chips cannot be selected.
+    pub fn selected(self, selected: bool) -> Self {
+        Self { selected, ..self }
+    }
+
+    /// Sets whether the [`Chip`] is elevated instead of outlined.
+    ///
+    /// Elevated chips are meant to be placed on top of images or other
+    /// content where an outline would be hard to see.
+    pub fn elevated(self, elevated: bool) -> Self {
+        Self { elevated, ..self }
+    }
+
+    /// Sets the message produced when the [`Chip`] is pressed.
+    ///
+    /// The [`Chip`] is disabled if it has no message.
+    pub fn on_press(self, on_press: Message) -> Self {
+        Self {
+            on_press: Some(on_press),
+            ..self
+        }
+    }
+
+    /// Sets the message produced when the [`Chip`] is pressed, if any.
+    pub fn on_press_maybe(self, on_press: Option<Message>) -> Self {
+        Self { on_press, ..self }
+    }
+
+    /// Sets the message produced when the remove icon of an input chip is
+    /// pressed.
+    ///
+    /// The remove icon is disabled along with the [`Chip`].
+    pub fn on_remove(self, on_remove: Message) -> Self {
+        Self {
+            on_remove: Some(on_remove),
+            ..self
+        }
+    }
+}
+
+impl<Message, Renderer> fmt::Debug for Chip<'_, Message, Renderer> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Chip")
+            .field("kind", &self.kind)
+            .field("is_avatar", &self.is_avatar)
+            .field("selected", &self.selected)
+            .field("elevated", &self.elevated)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Message, Renderer> From<Chip<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    fn from(chip: Chip<'a, Message, Renderer>) -> Self {
+        let selected = chip.selected && chip.kind != Kind::Assist;
+        let is_enabled = chip.on_press.is_some();
+
+        let leading = if selected && chip.kind == Kind::Filter {
+            Some(
+                Text::new(Renderer::CHECKMARK_ICON.to_string())
+                    .font(Renderer::ICON_FONT)
+                    .size(ICON_SIZE)
+                    .line_height(LineHeight::Relative(1.0))
+                    .into(),
+            )
+        } else {
+            chip.leading
+        };
+
+        let leading_size = if chip.is_avatar {
+            AVATAR_SIZE
+        } else {
+            ICON_SIZE
+        };
+
+        let trailing = chip.on_remove.filter(|_| chip.kind == Kind::Input).map(
+            |on_remove| {
+                Button::new(remove_icon().center())
+                    .width(ICON_SIZE)
+                    .height(ICON_SIZE)
+                    .padding(0)
+                    .on_press_maybe(is_enabled.then_some(on_remove))
+                    .style(remove)
+            },
+        );
+
+        let padding = Padding {
+            left: match (&leading, chip.is_avatar) {
+                (None, _) => 16.0,
+                (Some(_), false) => 8.0,
+                (Some(_), true) => 4.0,
+            },
+            right: if trailing.is_some() { 8.0 } else { 16.0 },
+            ..Padding::ZERO
+        };
+
+        let content = Row::new()
+            .push(leading.map(|leading| {
+                Container::new(leading)
+                    .center(leading_size)
+                    .clip(chip.is_avatar)
+            }))
+            .push(chip.label)
+            .push(trailing)
+            .spacing(8)
+            .height(Length::Fill)
+            .align_y(Alignment::Center);
+
+        Button::new(content)
+            .height(HEIGHT)
+            .padding(padding)
+            .on_press_maybe(chip.on_press)
+            .style(styled_with(chip.kind, selected, chip.elevated))
+            .into()
+    }
+}
+
+/// The icon of the remove button of an input chip.
+///
+/// The icon font of `iced` has no close icon, so the one of Material Symbols
+/// is used when the `icons` feature is enabled.
+fn remove_icon<'a, Renderer>() -> Text<'a, Theme, Renderer>
+where
+    Renderer: core::text::Renderer<Font = Font>,
+{
+    #[cfg(feature = "icons")]
+    let icon = crate::icon::icon(crate::icon::Icon::Close);
+
+    #[cfg(not(feature = "icons"))]
+    let icon = Text::new("\u{d7}");
+
+    icon.size(ICON_SIZE).line_height(LineHeight::Relative(1.0))
+}
+
+/// A chip of the given [`Kind`].
+pub fn styled_with(
+    kind: Kind,
+    selected: bool,
+    elevated: bool,
+) -> impl Fn(&Theme, Status) -> Style + Copy {
+    move |theme, status| {
+        let colors = theme.colors();
+        let surface = colors.surface;
+
+        let (background, foreground) = if selected {
+            (
+                colors.secondary.secondary_container,
+                colors.secondary.on_secondary_container,
+            )
+        } else {
+            (
+                if elevated {
+                    surface.surface_container.low
+                } else {
+                    Color::TRANSPARENT
+                },
+                if kind == Kind::Assist {
+                    surface.on_surface
+                } else {
+                    surface.on_surface_variant
+                },
+            )
+        };
+
+        let is_outlined = !selected && !elevated;
+
+        let style = button::themed(
+            theme,
+            background,
+            foreground,
+            surface.on_surface,
+            if is_outlined {
+                Color::TRANSPARENT
+            } else {
+                colors.shadow
+            },
+            u8::from(elevated),
+            status,
+        );
+
+        let border = Border {
+            color: if status == Status::Disabled {
+                theme.state_layer().disabled_container(surface.on_surface)
+            } else {
+                colors.outline.variant
+            },
+            width: if is_outlined { 1.0 } else { 0.0 },
+            radius: theme.shapes().small.into(),
+        };
+
+        Style { border, ..style }
+    }
+}
+
+/// The remove icon of an input chip.
+pub fn remove(theme: &Theme, status: Status) -> Style {
+    let surface = theme.colors().surface;
+
+    let style = button::themed(
+        theme,
+        Color::TRANSPARENT,
+        surface.on_surface_variant,
+        surface.on_surface,
+        Color::TRANSPARENT,
+        0,
+        status,
+    );
+
+    Style {
+        background: None,
+        ..style
+    }
+}
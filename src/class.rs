@@ -0,0 +1,172 @@
+//! Named style classes.
+//!
+//! Every widget module has a `Class` enum listing its named styles, which can
+//! be compared, listed, parsed and serialized. Arbitrary closures are still
+//! supported through the `Custom` variant, so `.style(closure)` keeps working.
+use std::fmt;
+
+/// An error returned when parsing an unknown style class name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseClassError {
+    /// The name that failed to parse.
+    pub name: String,
+}
+
+impl fmt::Display for ParseClassError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown style class `{}`", self.name)
+    }
+}
+
+impl std::error::Error for ParseClassError {}
+
+/// Generates a `Class` enum with one variant per named style function, plus a
+/// `Custom` variant holding a `StyleFn`.
+macro_rules! style_class {
+    (
+        $(#[$meta:meta])*
+        ($($arg:ident: $arg_ty:ty),*) -> $style:ty {
+            $($variant:ident => $function:ident,)+
+        }
+    ) => {
+        $(#[$meta])*
+        pub enum Class<'a> {
+            $($variant,)+
+            /// A style function that is not one of the named styles.
+            ///
+            /// It is never equal to another class and cannot be serialized.
+            Custom(StyleFn<'a, crate::Theme>),
+        }
+
+        impl Class<'_> {
+            /// The names of the named styles, as used by [`Display`] and
+            /// [`FromStr`].
+            ///
+            /// [`Display`]: std::fmt::Display
+            /// [`FromStr`]: std::str::FromStr
+            pub const NAMES: &'static [&'static str] =
+                &[$(stringify!($function)),+];
+
+            /// Returns the name of the class, or `None` for a custom class.
+            pub fn name(&self) -> Option<&'static str> {
+                match self {
+                    $(Self::$variant => Some(stringify!($function)),)+
+                    Self::Custom(_) => None,
+                }
+            }
+
+            /// Computes the style of the class.
+            pub fn style(
+                &self,
+                theme: &crate::Theme
+                $(, $arg: $arg_ty)*
+            ) -> $style {
+                let style: fn(&crate::Theme $(, $arg_ty)*) -> $style =
+                    match self {
+                        $(Self::$variant => $function,)+
+                        Self::Custom(f) => return f(theme $(, $arg)*),
+                    };
+
+                style(theme $(, $arg)*)
+            }
+        }
+
+        impl Class<'static> {
+            /// Returns an iterator over all the named styles.
+            pub fn all() -> impl Iterator<Item = Self> {
+                [$(Self::$variant),+].into_iter()
+            }
+        }
+
+        impl<'a> From<StyleFn<'a, crate::Theme>> for Class<'a> {
+            fn from(style: StyleFn<'a, crate::Theme>) -> Self {
+                Self::Custom(style)
+            }
+        }
+
+        impl std::fmt::Debug for Class<'_> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(Self::$variant => f.write_str(stringify!($variant)),)+
+                    Self::Custom(_) => f.write_str("Custom(..)"),
+                }
+            }
+        }
+
+        impl std::fmt::Display for Class<'_> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.name().unwrap_or("custom"))
+            }
+        }
+
+        impl PartialEq for Class<'_> {
+            fn eq(&self, other: &Self) -> bool {
+                match (self.name(), other.name()) {
+                    (Some(a), Some(b)) => a == b,
+                    _ => false,
+                }
+            }
+        }
+
+        impl std::str::FromStr for Class<'_> {
+            type Err = crate::class::ParseClassError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $(stringify!($function) => Ok(Self::$variant),)+
+                    _ => Err(crate::class::ParseClassError {
+                        name: s.to_owned(),
+                    }),
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for Class<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                match self.name() {
+                    Some(name) => serializer.serialize_str(name),
+                    None => Err(serde::ser::Error::custom(
+                        "custom style classes cannot be serialized",
+                    )),
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for Class<'_> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let name =
+                    <String as serde::Deserialize>::deserialize(deserializer)?;
+
+                name.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+pub(crate) use style_class;
+
+#[cfg(test)]
+mod tests {
+    use crate::button;
+
+    #[test]
+    fn names_round_trip() {
+        assert_eq!(button::Class::all().count(), button::Class::NAMES.len());
+
+        for class in button::Class::all() {
+            let name = class.to_string();
+
+            assert_eq!(name.parse::<button::Class<'_>>(), Ok(class));
+        }
+
+        assert!("unknown".parse::<button::Class<'_>>().is_err());
+    }
+}
@@ -0,0 +1,306 @@
+//! Simulate color vision deficiencies and check a [`ColorScheme`] for role
+//! pairs that become hard to tell apart.
+//!
+//! The simulation uses the matrices from [Machado et al. (2009)], applied in
+//! linear RGB. Color differences are measured as CIE76 ΔE in CIELAB space.
+//!
+//! [Machado et al. (2009)]: https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html
+use iced_widget::core::Color;
+
+use crate::{ColorScheme, Theme};
+
+/// The ΔE below which two colors are considered indistinguishable.
+pub const DISTINGUISHABLE_THRESHOLD: f32 = 10.0;
+
+/// A type of color vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Deficiency {
+    /// Reduced sensitivity to red light.
+    Protanopia,
+    /// Reduced sensitivity to green light.
+    Deuteranopia,
+    /// Reduced sensitivity to blue light.
+    Tritanopia,
+    /// Complete absence of color vision.
+    Achromatopsia,
+}
+
+impl Deficiency {
+    pub const ALL: &'static [Self] = &[
+        Self::Protanopia,
+        Self::Deuteranopia,
+        Self::Tritanopia,
+        Self::Achromatopsia,
+    ];
+
+    /// Simulates how the given [`Color`] is perceived with this [`Deficiency`].
+    ///
+    /// `severity` ranges from `0.0` (normal vision) to `1.0` (full deficiency).
+    pub fn simulate(self, color: Color, severity: f32) -> Color {
+        let severity = severity.clamp(0.0, 1.0);
+        let [r, g, b, a] = color.into_linear();
+
+        let [r2, g2, b2] = match self {
+            Self::Protanopia => apply(&PROTANOPIA, [r, g, b]),
+            Self::Deuteranopia => apply(&DEUTERANOPIA, [r, g, b]),
+            Self::Tritanopia => apply(&TRITANOPIA, [r, g, b]),
+            Self::Achromatopsia => {
+                let y = luminance([r, g, b]);
+                [y, y, y]
+            }
+        };
+
+        let lerp = |from: f32, to: f32| {
+            (from + (to - from) * severity).clamp(0.0, 1.0)
+        };
+
+        Color::from_linear_rgba(lerp(r, r2), lerp(g, g2), lerp(b, b2), a)
+    }
+}
+
+impl std::fmt::Display for Deficiency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Protanopia => "Protanopia",
+            Self::Deuteranopia => "Deuteranopia",
+            Self::Tritanopia => "Tritanopia",
+            Self::Achromatopsia => "Achromatopsia",
+        })
+    }
+}
+
+/// A pair of color roles that became indistinguishable under a [`Deficiency`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conflict {
+    /// The [`Deficiency`] the roles were simulated with.
+    pub deficiency: Deficiency,
+    /// The names of the two roles, e.g. `("primary.color", "error.color")`.
+    pub roles: (&'static str, &'static str),
+    /// The ΔE between the two roles before simulating.
+    pub original: f32,
+    /// The ΔE between the two roles after simulating.
+    pub simulated: f32,
+}
+
+type RolePair = (
+    &'static str,
+    fn(&ColorScheme) -> Color,
+    &'static str,
+    fn(&ColorScheme) -> Color,
+);
+
+/// The role pairs that are expected to stay distinguishable.
+const ROLE_PAIRS: &[RolePair] = &[
+    (
+        "primary.color",
+        |c| c.primary.color,
+        "error.color",
+        |c| c.error.color,
+    ),
+    (
+        "primary.color",
+        |c| c.primary.color,
+        "secondary.color",
+        |c| c.secondary.color,
+    ),
+    (
+        "primary.color",
+        |c| c.primary.color,
+        "tertiary.color",
+        |c| c.tertiary.color,
+    ),
+    (
+        "secondary.color",
+        |c| c.secondary.color,
+        "tertiary.color",
+        |c| c.tertiary.color,
+    ),
+    (
+        "secondary.color",
+        |c| c.secondary.color,
+        "error.color",
+        |c| c.error.color,
+    ),
+    (
+        "tertiary.color",
+        |c| c.tertiary.color,
+        "error.color",
+        |c| c.error.color,
+    ),
+    (
+        "primary.primary_container",
+        |c| c.primary.primary_container,
+        "error.error_container",
+        |c| c.error.error_container,
+    ),
+    (
+        "secondary.secondary_container",
+        |c| c.secondary.secondary_container,
+        "error.error_container",
+        |c| c.error.error_container,
+    ),
+    (
+        "tertiary.tertiary_container",
+        |c| c.tertiary.tertiary_container,
+        "error.error_container",
+        |c| c.error.error_container,
+    ),
+    (
+        "primary.color",
+        |c| c.primary.color,
+        "surface.color",
+        |c| c.surface.color,
+    ),
+    (
+        "error.color",
+        |c| c.error.color,
+        "surface.color",
+        |c| c.surface.color,
+    ),
+    (
+        "outline.color",
+        |c| c.outline.color,
+        "surface.color",
+        |c| c.surface.color,
+    ),
+];
+
+impl ColorScheme {
+    /// Creates a [`Theme`] that simulates how this [`ColorScheme`] is
+    /// perceived with the given [`Deficiency`].
+    ///
+    /// `severity` ranges from `0.0` (normal vision) to `1.0` (full deficiency).
+    pub fn simulate(&self, deficiency: Deficiency, severity: f32) -> Theme {
+        Theme::new(
+            deficiency.to_string(),
+            self.map(|color| deficiency.simulate(color, severity)),
+        )
+    }
+
+    /// Returns the role pairs that are distinguishable in this
+    /// [`ColorScheme`], but not when simulated with the given [`Deficiency`].
+    pub fn conflicts(
+        &self,
+        deficiency: Deficiency,
+        severity: f32,
+    ) -> Vec<Conflict> {
+        ROLE_PAIRS
+            .iter()
+            .filter_map(|(first, a, second, b)| {
+                let (a, b) = (a(self), b(self));
+
+                let original = difference(a, b);
+                let simulated = difference(
+                    deficiency.simulate(a, severity),
+                    deficiency.simulate(b, severity),
+                );
+
+                (original >= DISTINGUISHABLE_THRESHOLD
+                    && simulated < DISTINGUISHABLE_THRESHOLD)
+                    .then_some(Conflict {
+                        deficiency,
+                        roles: (first, second),
+                        original,
+                        simulated,
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns the [`Conflict`]s for every [`Deficiency`] at full severity.
+    pub fn check_color_vision(&self) -> Vec<Conflict> {
+        Deficiency::ALL
+            .iter()
+            .flat_map(|deficiency| self.conflicts(*deficiency, 1.0))
+            .collect()
+    }
+}
+
+const PROTANOPIA: [[f32; 3]; 3] = [
+    [0.152_286, 1.052_583, -0.204_868],
+    [0.114_503, 0.786_281, 0.099_216],
+    [-0.003_882, -0.048_116, 1.051_998],
+];
+
+const DEUTERANOPIA: [[f32; 3]; 3] = [
+    [0.367_322, 0.860_646, -0.227_968],
+    [0.280_085, 0.672_501, 0.047_413],
+    [-0.011_820, 0.042_940, 0.968_881],
+];
+
+const TRITANOPIA: [[f32; 3]; 3] = [
+    [1.255_528, -0.076_749, -0.178_779],
+    [-0.078_411, 0.930_809, 0.147_602],
+    [0.004_733, 0.691_367, 0.303_900],
+];
+
+fn apply(matrix: &[[f32; 3]; 3], rgb: [f32; 3]) -> [f32; 3] {
+    matrix.map(|row| row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2])
+}
+
+fn luminance([r, g, b]: [f32; 3]) -> f32 {
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// Converts a [`Color`] into CIELAB, assuming a D65 white point.
+fn to_lab(color: Color) -> [f32; 3] {
+    let [r, g, b, _] = color.into_linear();
+
+    let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.950_47;
+    let y = luminance([r, g, b]);
+    let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.088_83;
+
+    let f = |t: f32| {
+        if t > 0.008_856 {
+            t.cbrt()
+        } else {
+            7.787 * t + 16.0 / 116.0
+        }
+    };
+
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// The CIE76 ΔE between two colors.
+pub fn difference(a: Color, b: Color) -> f32 {
+    let [l1, a1, b1] = to_lab(a);
+    let [l2, a2, b2] = to_lab(b);
+
+    ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Color, Deficiency, difference};
+
+    #[test]
+    fn achromatopsia_is_gray() {
+        let [r, g, b, _] = Deficiency::Achromatopsia
+            .simulate(Color::from_rgb(0.8, 0.2, 0.4), 1.0)
+            .into_rgba8();
+
+        assert_eq!(r, g);
+        assert_eq!(g, b);
+    }
+
+    #[test]
+    fn protanopia_reduces_red_green_difference() {
+        let red = Color::from_rgb8(0xd0, 0x40, 0x30);
+        let green = Color::from_rgb8(0x6a, 0x8a, 0x30);
+
+        assert!(difference(red, green) > 10.0);
+        assert_eq!(
+            Deficiency::Protanopia.simulate(red, 0.0).into_rgba8(),
+            red.into_rgba8()
+        );
+        assert!(
+            difference(
+                Deficiency::Protanopia.simulate(red, 1.0),
+                Deficiency::Protanopia.simulate(green, 1.0),
+            ) < difference(red, green)
+        );
+    }
+}
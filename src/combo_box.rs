@@ -1,5 +1,11 @@
 use iced_widget::combo_box::Catalog;
+use iced_widget::text_input::{Status, Style};
 
 use super::Theme;
+use crate::text_input;
 
 impl Catalog for Theme {}
+
+pub fn error(theme: &Theme, status: Status) -> Style {
+    text_input::error(theme, status)
+}
@@ -1,23 +1,62 @@
 use iced_widget::container::{Catalog, Style, StyleFn};
-use iced_widget::core::{Background, Border, border};
+use iced_widget::core::{Background, Border, Shadow, border};
 
-use super::Theme;
+use super::{Role, Theme};
+use crate::class::style_class;
+use crate::overrides::Component;
+use crate::shape::Shape;
+use crate::utils::State;
+use crate::{card, elevation};
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(transparent)
+        Class::Transparent
     }
 
     fn style(&self, class: &Self::Class<'_>) -> Style {
-        class(self)
+        class.style(self)
     }
 }
 
-pub fn transparent(_theme: &Theme) -> Style {
+style_class! {
+    /// The named styles of a container.
+    () -> Style {
+        Transparent => transparent,
+        Primary => primary,
+        PrimaryContainer => primary_container,
+        Secondary => secondary,
+        SecondaryContainer => secondary_container,
+        Tertiary => tertiary,
+        TertiaryContainer => tertiary_container,
+        Error => error,
+        ErrorContainer => error_container,
+        Surface => surface,
+        SurfaceContainerLowest => surface_container_lowest,
+        SurfaceContainerLow => surface_container_low,
+        SurfaceContainer => surface_container,
+        SurfaceContainerHigh => surface_container_high,
+        SurfaceContainerHighest => surface_container_highest,
+        InverseSurface => inverse_surface,
+        Outlined => outlined,
+        ElevatedCard => elevated_card,
+        FilledCard => filled_card,
+        OutlinedCard => outlined_card,
+        Dialog => dialog,
+        NavigationDrawer => navigation_drawer,
+        ModalNavigationDrawer => modal_navigation_drawer,
+        Scrim => scrim,
+    }
+}
+
+pub fn transparent(theme: &Theme) -> Style {
+    let overrides =
+        theme.component_override(Component::Container, State::Enabled);
+
     Style {
-        border: border::rounded(4),
+        border: overrides.border(border::rounded(theme.shapes().extra_small)),
+        shadow: overrides.shadow(theme, Shadow::default()),
         ..Style::default()
     }
 }
@@ -28,7 +67,7 @@ pub fn primary(theme: &Theme) -> Style {
     Style {
         background: Some(Background::Color(primary.color)),
         text_color: Some(primary.on_primary),
-        border: border::rounded(4),
+        border: border::rounded(theme.shapes().extra_small),
         ..Style::default()
     }
 }
@@ -39,7 +78,7 @@ pub fn primary_container(theme: &Theme) -> Style {
     Style {
         background: Some(Background::Color(primary.primary_container)),
         text_color: Some(primary.on_primary_container),
-        border: border::rounded(8),
+        border: border::rounded(theme.shapes().small),
         ..Style::default()
     }
 }
@@ -50,7 +89,7 @@ pub fn secondary(theme: &Theme) -> Style {
     Style {
         background: Some(Background::Color(secondary.color)),
         text_color: Some(secondary.on_secondary),
-        border: border::rounded(4),
+        border: border::rounded(theme.shapes().extra_small),
         ..Style::default()
     }
 }
@@ -61,7 +100,7 @@ pub fn secondary_container(theme: &Theme) -> Style {
     Style {
         background: Some(Background::Color(secondary.secondary_container)),
         text_color: Some(secondary.on_secondary_container),
-        border: border::rounded(8),
+        border: border::rounded(theme.shapes().small),
         ..Style::default()
     }
 }
@@ -72,7 +111,7 @@ pub fn tertiary(theme: &Theme) -> Style {
     Style {
         background: Some(Background::Color(tertiary.color)),
         text_color: Some(tertiary.on_tertiary),
-        border: border::rounded(4),
+        border: border::rounded(theme.shapes().extra_small),
         ..Style::default()
     }
 }
@@ -83,7 +122,7 @@ pub fn tertiary_container(theme: &Theme) -> Style {
     Style {
         background: Some(Background::Color(tertiary.tertiary_container)),
         text_color: Some(tertiary.on_tertiary_container),
-        border: border::rounded(8),
+        border: border::rounded(theme.shapes().small),
         ..Style::default()
     }
 }
@@ -94,7 +133,7 @@ pub fn error(theme: &Theme) -> Style {
     Style {
         background: Some(Background::Color(error.color)),
         text_color: Some(error.on_error),
-        border: border::rounded(4),
+        border: border::rounded(theme.shapes().extra_small),
         ..Style::default()
     }
 }
@@ -105,7 +144,7 @@ pub fn error_container(theme: &Theme) -> Style {
     Style {
         background: Some(Background::Color(error.error_container)),
         text_color: Some(error.on_error_container),
-        border: border::rounded(8),
+        border: border::rounded(theme.shapes().small),
         ..Style::default()
     }
 }
@@ -116,7 +155,7 @@ pub fn surface(theme: &Theme) -> Style {
     Style {
         background: Some(Background::Color(surface.color)),
         text_color: Some(surface.on_surface),
-        border: border::rounded(4),
+        border: border::rounded(theme.shapes().extra_small),
         ..Style::default()
     }
 }
@@ -127,7 +166,7 @@ pub fn surface_container_lowest(theme: &Theme) -> Style {
     Style {
         background: Some(Background::Color(surface.surface_container.lowest)),
         text_color: Some(surface.on_surface),
-        border: border::rounded(8),
+        border: border::rounded(theme.shapes().small),
         ..Style::default()
     }
 }
@@ -138,7 +177,7 @@ pub fn surface_container_low(theme: &Theme) -> Style {
     Style {
         background: Some(Background::Color(surface.surface_container.low)),
         text_color: Some(surface.on_surface),
-        border: border::rounded(8),
+        border: border::rounded(theme.shapes().small),
         ..Style::default()
     }
 }
@@ -149,7 +188,7 @@ pub fn surface_container(theme: &Theme) -> Style {
     Style {
         background: Some(Background::Color(surface.surface_container.base)),
         text_color: Some(surface.on_surface),
-        border: border::rounded(8),
+        border: border::rounded(theme.shapes().small),
         ..Style::default()
     }
 }
@@ -160,7 +199,7 @@ pub fn surface_container_high(theme: &Theme) -> Style {
     Style {
         background: Some(Background::Color(surface.surface_container.high)),
         text_color: Some(surface.on_surface),
-        border: border::rounded(8),
+        border: border::rounded(theme.shapes().small),
         ..Style::default()
     }
 }
@@ -171,7 +210,7 @@ pub fn surface_container_highest(theme: &Theme) -> Style {
     Style {
         background: Some(Background::Color(surface.surface_container.highest)),
         text_color: Some(surface.on_surface),
-        border: border::rounded(8),
+        border: border::rounded(theme.shapes().small),
         ..Style::default()
     }
 }
@@ -182,7 +221,7 @@ pub fn inverse_surface(theme: &Theme) -> Style {
     Style {
         background: Some(Background::Color(inverse.inverse_surface)),
         text_color: Some(inverse.inverse_on_surface),
-        border: border::rounded(4),
+        border: border::rounded(theme.shapes().extra_small),
         ..Style::default()
     }
 }
@@ -199,3 +238,242 @@ pub fn outlined(theme: &Theme) -> Style {
         ..base
     }
 }
+
+/// The style of an elevated [`Card`](card::Card).
+pub fn elevated_card(theme: &Theme) -> Style {
+    card::styled(theme, card::Kind::Elevated, State::Enabled)
+}
+
+/// The style of a filled [`Card`](card::Card).
+pub fn filled_card(theme: &Theme) -> Style {
+    card::styled(theme, card::Kind::Filled, State::Enabled)
+}
+
+/// The style of an outlined [`Card`](card::Card).
+pub fn outlined_card(theme: &Theme) -> Style {
+    card::styled(theme, card::Kind::Outlined, State::Enabled)
+}
+
+/// The style of the container of a dialog.
+pub fn dialog(theme: &Theme) -> Style {
+    let surface = theme.colors().surface;
+
+    Style {
+        background: Some(Background::Color(surface.surface_container.high)),
+        text_color: Some(surface.on_surface_variant),
+        border: border::rounded(theme.shapes().extra_large),
+        ..Style::default()
+    }
+}
+
+/// The style of a standard
+/// [`NavigationDrawer`](crate::navigation_drawer::NavigationDrawer).
+pub fn navigation_drawer(theme: &Theme) -> Style {
+    let surface = theme.colors().surface;
+
+    Style {
+        background: Some(Background::Color(surface.color)),
+        text_color: Some(surface.on_surface_variant),
+        ..Style::default()
+    }
+}
+
+/// The style of a modal
+/// [`NavigationDrawer`](crate::navigation_drawer::NavigationDrawer).
+pub fn modal_navigation_drawer(theme: &Theme) -> Style {
+    let colors = theme.colors();
+
+    Style {
+        background: Some(Background::Color(
+            colors.surface.surface_container.low,
+        )),
+        text_color: Some(colors.surface.on_surface_variant),
+        border: border::rounded(border::right(theme.shapes().large)),
+        shadow: theme.elevation().shadow(1, colors.shadow),
+        ..Style::default()
+    }
+}
+
+/// The backdrop behind modal components, like a modal
+/// [`NavigationDrawer`](crate::navigation_drawer::NavigationDrawer).
+pub fn scrim(theme: &Theme) -> Style {
+    Style {
+        background: Some(Background::Color(theme.colors().scrim)),
+        ..Style::default()
+    }
+}
+
+pub fn elevated(level: u8) -> impl Fn(&Theme) -> Style {
+    move |theme| {
+        let colors = theme.colors();
+
+        Style {
+            background: Some(Background::Color(elevation::surface(
+                theme, level,
+            ))),
+            text_color: Some(colors.surface.on_surface),
+            border: border::rounded(theme.shapes().small),
+            shadow: theme.elevation().shadow(level, colors.shadow),
+            ..Style::default()
+        }
+    }
+}
+
+pub fn tinted(level: u8) -> impl Fn(&Theme) -> Style {
+    move |theme| {
+        let colors = theme.colors();
+
+        Style {
+            background: Some(Background::Color(elevation::tinted_surface(
+                theme, level,
+            ))),
+            text_color: Some(colors.surface.on_surface),
+            border: border::rounded(theme.shapes().small),
+            shadow: theme.elevation().shadow(level, colors.shadow),
+            ..Style::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Transparent,
+    Filled,
+    Tonal,
+    Surface,
+    Outlined,
+}
+
+/// A builder for container styles.
+///
+/// # Example
+///
+/// ```no_run
+/// use iced::widget::{Container, container};
+/// use iced_material::container::ContainerStyle;
+/// use iced_material::shape::Shape;
+/// use iced_material::{Role, Theme};
+///
+/// let style = ContainerStyle::surface().shape(Shape::Medium).elevation(1);
+/// let banner = ContainerStyle::tonal().role(Role::Error);
+///
+/// let card: Container<'_, (), Theme> =
+///     container("Card").style(style.into_fn());
+/// let error: Container<'_, (), Theme> =
+///     container("Error").style(banner.into_fn());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContainerStyle {
+    kind: Kind,
+    role: Role,
+    shape: Shape,
+    elevation: u8,
+}
+
+impl ContainerStyle {
+    const fn new(kind: Kind, shape: Shape) -> Self {
+        Self {
+            kind,
+            role: Role::Primary,
+            shape,
+            elevation: 0,
+        }
+    }
+
+    pub const fn transparent() -> Self {
+        Self::new(Kind::Transparent, Shape::ExtraSmall)
+    }
+
+    /// A container filled with the color of its [`Role`].
+    pub const fn filled() -> Self {
+        Self::new(Kind::Filled, Shape::ExtraSmall)
+    }
+
+    /// A container filled with the container color of its [`Role`].
+    pub const fn tonal() -> Self {
+        Self::new(Kind::Tonal, Shape::ExtraSmall)
+    }
+
+    /// A surface container, whose color follows its elevation level.
+    pub const fn surface() -> Self {
+        Self::new(Kind::Surface, Shape::Small)
+    }
+
+    pub const fn outlined() -> Self {
+        Self::new(Kind::Outlined, Shape::ExtraSmall)
+    }
+
+    /// Sets the color [`Role`] of the container.
+    pub const fn role(self, role: Role) -> Self {
+        Self { role, ..self }
+    }
+
+    /// Sets the [`Shape`] of the container.
+    pub const fn shape(self, shape: Shape) -> Self {
+        Self { shape, ..self }
+    }
+
+    /// Sets the elevation level of the container.
+    pub const fn elevation(self, elevation: u8) -> Self {
+        Self { elevation, ..self }
+    }
+
+    /// Turns the style into a function that can be passed to the `style`
+    /// method of a [`Container`].
+    ///
+    /// [`Container`]: iced_widget::Container
+    pub fn into_fn(self) -> impl Fn(&Theme) -> Style + Copy {
+        move |theme| self.style(theme)
+    }
+
+    /// Computes the [`Style`] of the container.
+    pub fn style(&self, theme: &Theme) -> Style {
+        let colors = theme.colors();
+        let group = colors.role(self.role);
+
+        let (background, text_color) = match self.kind {
+            Kind::Transparent | Kind::Outlined => (None, None),
+            Kind::Filled => (Some(group.color), Some(group.on_color)),
+            Kind::Tonal => (Some(group.container), Some(group.on_container)),
+            Kind::Surface => (
+                Some(elevation::surface(theme, self.elevation)),
+                Some(colors.surface.on_surface),
+            ),
+        };
+
+        let radius = theme.shapes().radius(self.shape).into();
+
+        let border = if self.kind == Kind::Outlined {
+            Border {
+                color: colors.outline.color,
+                width: 2.0,
+                radius,
+            }
+        } else {
+            Border {
+                radius,
+                ..Border::default()
+            }
+        };
+
+        Style {
+            background: background.map(Background::Color),
+            text_color,
+            border,
+            shadow: theme.elevation().shadow(self.elevation, colors.shadow),
+            ..Style::default()
+        }
+    }
+}
+
+impl From<ContainerStyle> for StyleFn<'_, Theme> {
+    fn from(style: ContainerStyle) -> Self {
+        Box::new(move |theme| style.style(theme))
+    }
+}
+
+impl From<ContainerStyle> for Class<'_> {
+    fn from(style: ContainerStyle) -> Self {
+        Self::Custom(style.into())
+    }
+}
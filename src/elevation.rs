@@ -0,0 +1,96 @@
+//! The Material 3 [elevation](https://m3.material.io/styles/elevation/tokens) levels.
+use iced_widget::core::{Color, Shadow};
+
+use crate::Theme;
+use crate::utils::{mix, shadow_from_elevation};
+
+/// A single elevation level.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Level {
+    /// The height of the level, in logical pixels. Used for the [`Shadow`].
+    pub height: f32,
+    /// The opacity of the surface tint overlay at this level.
+    pub tint: f32,
+}
+
+impl Level {
+    const fn new(height: f32, tint: f32) -> Self {
+        Self { height, tint }
+    }
+}
+
+/// A [`Theme`]'s elevation levels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Elevation {
+    pub level0: Level,
+    pub level1: Level,
+    pub level2: Level,
+    pub level3: Level,
+    pub level4: Level,
+    pub level5: Level,
+}
+
+impl Elevation {
+    /// The default Material 3 elevation levels.
+    pub const DEFAULT: Self = Self {
+        level0: Level::new(0.0, 0.0),
+        level1: Level::new(1.0, 0.05),
+        level2: Level::new(3.0, 0.08),
+        level3: Level::new(6.0, 0.11),
+        level4: Level::new(8.0, 0.12),
+        level5: Level::new(12.0, 0.14),
+    };
+
+    /// Returns the given [`Level`]. Levels above 5 are treated as 5.
+    pub fn level(&self, level: u8) -> Level {
+        match level {
+            0 => self.level0,
+            1 => self.level1,
+            2 => self.level2,
+            3 => self.level3,
+            4 => self.level4,
+            _ => self.level5,
+        }
+    }
+
+    /// Returns the [`Shadow`] of the given level.
+    pub fn shadow(&self, level: u8, color: Color) -> Shadow {
+        shadow_from_elevation(self.level(level).height, color)
+    }
+}
+
+impl Default for Elevation {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Returns the surface container color that matches the given level.
+pub fn surface(theme: &Theme, level: u8) -> Color {
+    let surface = theme.colors().surface;
+
+    match level {
+        0 => surface.color,
+        1 => surface.surface_container.low,
+        2 => surface.surface_container.base,
+        3 => surface.surface_container.high,
+        _ => surface.surface_container.highest,
+    }
+}
+
+/// Returns the surface color with the surface tint overlay of the given level
+/// applied.
+///
+/// The surface tint is the primary color of the [`Theme`].
+pub fn tinted_surface(theme: &Theme, level: u8) -> Color {
+    let colors = theme.colors();
+
+    mix(
+        colors.surface.color,
+        colors.primary.color,
+        theme.elevation().level(level).tint,
+    )
+}
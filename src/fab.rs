@@ -0,0 +1,261 @@
+//! [Floating action buttons](https://m3.material.io/components/floating-action-button/overview).
+//!
+//! FABs are regular `iced` buttons with a fixed size and one of the styles
+//! in this module. They use the container colors of a [`Role`], sit at
+//! elevation level 3 and rise to level 4 when hovered.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced_material::fab;
+//! use iced_material::{Role, Theme};
+//! use iced_widget::{column, text};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Compose,
+//!     ToggleMenu,
+//!     Share,
+//! }
+//!
+//! # fn view<'a>(is_open: bool) -> iced_widget::core::Element<'a, Message, Theme, iced_widget::Renderer> {
+//! let compose = fab::extended(Role::Primary, text("+"), text("Compose"))
+//!     .on_press(Message::Compose);
+//!
+//! let menu = fab::menu(
+//!     Role::Tertiary,
+//!     is_open,
+//!     Message::ToggleMenu,
+//!     text("+"),
+//!     text("x"),
+//!     [fab::menu_item(Role::Tertiary, text(">"), text("Share"))
+//!         .on_press(Message::Share)
+//!         .into()],
+//! );
+//!
+//! column![compose, menu].into()
+//! # }
+//! ```
+use iced_widget::button::{Status, Style};
+use iced_widget::core::{self, Alignment, Element, Length, Padding, border};
+use iced_widget::{Button, Column, Container, Row};
+
+use crate::shape::Shape;
+use crate::{Role, Theme, button};
+
+/// The elevation level of a FAB at rest.
+const ELEVATION: u8 = 3;
+
+/// The size of a FAB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Size {
+    Small,
+    #[default]
+    Regular,
+    Large,
+}
+
+impl Size {
+    /// The width and height of the FAB, in logical pixels.
+    pub const fn length(self) -> f32 {
+        match self {
+            Self::Small => 40.0,
+            Self::Regular => 56.0,
+            Self::Large => 96.0,
+        }
+    }
+
+    /// The recommended size of the FAB's icon, in logical pixels.
+    pub const fn icon_size(self) -> f32 {
+        match self {
+            Self::Small | Self::Regular => 24.0,
+            Self::Large => 36.0,
+        }
+    }
+
+    /// The [`Shape`] of the FAB.
+    pub const fn shape(self) -> Shape {
+        match self {
+            Self::Small => Shape::Medium,
+            Self::Regular => Shape::Large,
+            Self::Large => Shape::ExtraLarge,
+        }
+    }
+}
+
+/// Creates a regular FAB using the primary [`Role`].
+pub fn fab<'a, Message, Renderer>(
+    icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Button<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer + 'a,
+    Message: 'a,
+{
+    fab_with(Size::Regular, Role::Primary, icon)
+}
+
+/// Creates a FAB of the given [`Size`] using the given [`Role`].
+pub fn fab_with<'a, Message, Renderer>(
+    size: Size,
+    role: Role,
+    icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Button<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer + 'a,
+    Message: 'a,
+{
+    Button::new(Container::new(icon).center(Length::Fill))
+        .width(size.length())
+        .height(size.length())
+        .padding(0)
+        .style(styled_with(role, size.shape()))
+}
+
+/// Creates an extended FAB, showing a label next to its icon.
+pub fn extended<'a, Message, Renderer>(
+    role: Role,
+    icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+    label: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Button<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer + 'a,
+    Message: 'a,
+{
+    Button::new(
+        Row::new()
+            .push(icon)
+            .push(label)
+            .spacing(12)
+            .height(Length::Fill)
+            .align_y(Alignment::Center),
+    )
+    .height(Size::Regular.length())
+    .padding(Padding::from([0, 16]))
+    .style(styled_with(role, Shape::Large))
+}
+
+/// Creates a FAB menu.
+///
+/// When closed, the menu is a regular FAB showing `icon`. When open, the
+/// given items are stacked above a round button showing `close_icon`.
+/// Both buttons produce `on_toggle` when pressed.
+///
+/// The items are usually created with [`menu_item`]. `Message` has to be
+/// [`Clone`], as for any button turned into an [`Element`].
+pub fn menu<'a, Message, Renderer>(
+    role: Role,
+    is_open: bool,
+    on_toggle: Message,
+    icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+    close_icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+    items: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Column<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer + 'a,
+    Message: Clone + 'a,
+{
+    if is_open {
+        let close =
+            Button::new(Container::new(close_icon).center(Length::Fill))
+                .width(Size::Regular.length())
+                .height(Size::Regular.length())
+                .padding(0)
+                .style(close_with(role))
+                .on_press(on_toggle);
+
+        Column::with_children(items).push(close)
+    } else {
+        Column::new()
+            .push(fab_with(Size::Regular, role, icon).on_press(on_toggle))
+    }
+    .spacing(8)
+    .align_x(Alignment::End)
+}
+
+/// Creates an item of a FAB [`menu`].
+pub fn menu_item<'a, Message, Renderer>(
+    role: Role,
+    icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+    label: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Button<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer + 'a,
+    Message: 'a,
+{
+    Button::new(
+        Row::new()
+            .push(icon)
+            .push(label)
+            .spacing(8)
+            .height(Length::Fill)
+            .align_y(Alignment::Center),
+    )
+    .height(Size::Regular.length())
+    .padding(Padding::from([0, 24]))
+    .style(styled_with(role, Shape::Full))
+}
+
+/// A regular FAB using the primary container color.
+pub fn primary(theme: &Theme, status: Status) -> Style {
+    styled_with(Role::Primary, Shape::Large)(theme, status)
+}
+
+/// A regular FAB using the secondary container color.
+pub fn secondary(theme: &Theme, status: Status) -> Style {
+    styled_with(Role::Secondary, Shape::Large)(theme, status)
+}
+
+/// A regular FAB using the tertiary container color.
+pub fn tertiary(theme: &Theme, status: Status) -> Style {
+    styled_with(Role::Tertiary, Shape::Large)(theme, status)
+}
+
+/// A FAB using the container color of the given [`Role`], with the corners
+/// of the given [`Shape`].
+pub fn styled_with(
+    role: Role,
+    shape: Shape,
+) -> impl Fn(&Theme, Status) -> Style + Copy {
+    move |theme, status| {
+        let colors = theme.colors();
+        let group = colors.role(role);
+
+        styled(theme, group.container, group.on_container, shape, status)
+    }
+}
+
+/// The round button closing an open FAB [`menu`], using the color of the
+/// given [`Role`].
+pub fn close_with(role: Role) -> impl Fn(&Theme, Status) -> Style + Copy {
+    move |theme, status| {
+        let group = theme.colors().role(role);
+
+        styled(theme, group.color, group.on_color, Shape::Full, status)
+    }
+}
+
+fn styled(
+    theme: &Theme,
+    background: core::Color,
+    foreground: core::Color,
+    shape: Shape,
+    status: Status,
+) -> Style {
+    let colors = theme.colors();
+
+    let style = button::themed(
+        theme,
+        background,
+        foreground,
+        colors.surface.on_surface,
+        colors.shadow,
+        ELEVATION,
+        status,
+    );
+
+    Style {
+        border: border::rounded(theme.shapes().radius(shape)),
+        ..style
+    }
+}
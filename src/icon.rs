@@ -0,0 +1,246 @@
+//! The bundled [Material Symbols](https://fonts.google.com/icons) icon fonts.
+//!
+//! `iced` can only select the weight of a variable font, so every
+//! combination of the fill, grade and optical size axes is bundled as a
+//! static instance of the [`Variant`]'s font, with the weight axis left
+//! variable.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced_material::icon::{self, Grade, Icon, IconStyle, Variant};
+//! use iced_material::{Role, Theme};
+//! use iced_widget::core::font::Weight;
+//!
+//! # fn view<'a>() -> iced_widget::Text<'a, Theme> {
+//! icon::icon_with(
+//!     Icon::Favorite,
+//!     IconStyle {
+//!         variant: Variant::Rounded,
+//!         fill: true,
+//!         weight: Weight::Light,
+//!         grade: Grade::High,
+//!         ..IconStyle::DEFAULT
+//!     },
+//! )
+//! .style(icon::colored(Role::Primary))
+//! # }
+//! ```
+//!
+//! The fonts have to be loaded by the application, see [`settings`] and
+//! [`load`].
+use std::fmt;
+
+use iced_widget::Text;
+use iced_widget::core::Settings;
+use iced_widget::core::font::{Family, Font, Weight};
+use iced_widget::core::text::{self, LineHeight, Shaping};
+use iced_widget::runtime::Task;
+use iced_widget::runtime::font::{self, Error};
+
+use crate::{Role, Theme};
+
+include!(concat!(env!("OUT_DIR"), "/icon_fonts.rs"));
+
+/// Adds the bundled [`FONTS`] to the given [`Settings`].
+pub fn settings(settings: Settings) -> Settings {
+    let mut fonts = settings.fonts;
+    fonts.extend(FONTS.iter().map(|bytes| (*bytes).into()));
+
+    Settings { fonts, ..settings }
+}
+
+/// Loads every bundled font file at runtime.
+pub fn load() -> Task<Result<(), Error>> {
+    Task::batch(FONTS.iter().map(|bytes| font::load(*bytes)))
+}
+
+/// The style of the Material Symbols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Variant {
+    #[default]
+    Outlined,
+    Rounded,
+    Sharp,
+}
+
+/// The grade of an icon, which adjusts its thickness without changing its
+/// size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Grade {
+    /// Grade `-25`, for light icons on dark backgrounds.
+    Low,
+    /// Grade `0`.
+    #[default]
+    Normal,
+    /// Grade `200`, for emphasis.
+    High,
+}
+
+impl Grade {
+    /// The value of the grade axis.
+    pub const fn value(self) -> i16 {
+        match self {
+            Self::Low => -25,
+            Self::Normal => 0,
+            Self::High => 200,
+        }
+    }
+}
+
+/// The optical size of an icon, which the icon should be displayed at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OpticalSize {
+    /// `20` pixels.
+    Small,
+    /// `24` pixels.
+    #[default]
+    Medium,
+    /// `40` pixels.
+    Large,
+    /// `48` pixels.
+    ExtraLarge,
+}
+
+impl OpticalSize {
+    /// The size, in logical pixels.
+    pub const fn size(self) -> f32 {
+        match self {
+            Self::Small => 20.0,
+            Self::Medium => 24.0,
+            Self::Large => 40.0,
+            Self::ExtraLarge => 48.0,
+        }
+    }
+}
+
+/// The variable axes of an icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IconStyle {
+    pub variant: Variant,
+    /// Whether the icon is filled.
+    pub fill: bool,
+    pub weight: Weight,
+    pub grade: Grade,
+    pub optical_size: OpticalSize,
+}
+
+impl IconStyle {
+    pub const DEFAULT: Self = Self {
+        variant: Variant::Outlined,
+        fill: false,
+        weight: Weight::Normal,
+        grade: Grade::Normal,
+        optical_size: OpticalSize::Medium,
+    };
+
+    /// The [`Font`] of the bundled instance matching the [`IconStyle`].
+    pub const fn font(self) -> Font {
+        Font {
+            family: Family::Name(family(
+                self.variant,
+                self.fill,
+                self.grade,
+                self.optical_size,
+            )),
+            weight: self.weight,
+            ..Font::DEFAULT
+        }
+    }
+}
+
+impl Default for IconStyle {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Creates a [`Text`] widget displaying the given [`Icon`].
+///
+/// The icon takes the text color of its parent, use [`colored`] to give it
+/// the color of a [`Role`].
+pub fn icon<'a, Renderer>(icon: Icon) -> Text<'a, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    icon_with(icon, IconStyle::DEFAULT)
+}
+
+/// Creates a [`Text`] widget displaying the given [`Icon`] with the given
+/// [`IconStyle`], at its optical size.
+pub fn icon_with<'a, Renderer>(
+    icon: Icon,
+    style: IconStyle,
+) -> Text<'a, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    Text::new(icon.codepoint().to_string())
+        .font(style.font())
+        .size(style.optical_size.size())
+        .line_height(LineHeight::Relative(1.0))
+        .shaping(Shaping::Basic)
+}
+
+/// Colors an icon with the color of the given [`Role`].
+pub fn colored(
+    role: Role,
+) -> impl Fn(&Theme) -> iced_widget::text::Style + Copy {
+    move |theme| iced_widget::text::Style {
+        color: Some(theme.colors().role(role).color),
+    }
+}
+
+macro_rules! icons {
+    ($($variant:ident => $name:literal, $codepoint:literal,)+) => {
+        /// A Material Symbols icon.
+        ///
+        /// The variants are generated from the codepoints of the bundled
+        /// fonts.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize)
+        )]
+        pub enum Icon {
+            $($variant,)+
+        }
+
+        impl Icon {
+            pub const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+            /// The name of the icon, as used by Material Symbols.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)+
+                }
+            }
+
+            /// The codepoint of the icon in the Material Symbols fonts.
+            pub const fn codepoint(self) -> char {
+                match self {
+                    $(Self::$variant => $codepoint,)+
+                }
+            }
+
+            /// Returns the icon with the given Material Symbols name.
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $($name => Some(Self::$variant),)+
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+impl fmt::Display for Icon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/icons.rs"));
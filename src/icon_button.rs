@@ -0,0 +1,401 @@
+//! [Icon buttons](https://m3.material.io/components/icon-buttons/overview).
+//!
+//! Icon buttons come in four [`Variant`]s and can act as toggles. Since
+//! `iced`'s [`Status`] cannot express whether a button is selected, toggle
+//! buttons are styled with [`toggle_with`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced_material::Theme;
+//! use iced_material::icon_button::{self, Variant};
+//! use iced_widget::{row, text};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Back,
+//!     Favorite(bool),
+//! }
+//!
+//! # fn view<'a>(is_favorite: bool) -> iced_widget::core::Element<'a, Message, Theme, iced_widget::Renderer> {
+//! row![
+//!     icon_button::icon_button(
+//!         Variant::Standard,
+//!         text("<"),
+//!         Some(Message::Back),
+//!     ),
+//!     icon_button::toggle(
+//!         Variant::Filled,
+//!         is_favorite,
+//!         text("*"),
+//!         Some(Message::Favorite(!is_favorite)),
+//!     ),
+//! ]
+//! .into()
+//! # }
+//! ```
+use iced_widget::button::{Status, Style};
+use iced_widget::core::layout::{self, Layout, Limits};
+use iced_widget::core::widget::{Operation, Tree};
+use iced_widget::core::{
+    self, Background, Border, Clipboard, Color, Element, Event, Length, Point,
+    Rectangle, Shell, Size, Vector, Widget, mouse, overlay, renderer,
+};
+use iced_widget::{Button, Container};
+
+use crate::Theme;
+
+/// The width and height of the button's container, in logical pixels.
+pub const SIZE: f32 = 40.0;
+
+/// The width and height of the button's touch target, in logical pixels.
+pub const TARGET_SIZE: f32 = 48.0;
+
+/// The visual variant of an icon button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Variant {
+    #[default]
+    Standard,
+    Filled,
+    Tonal,
+    Outlined,
+}
+
+/// Creates an icon button of the given [`Variant`].
+///
+/// The button is disabled when `on_press` is `None`.
+pub fn icon_button<'a, Message, Renderer>(
+    variant: Variant,
+    icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_press: Option<Message>,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: core::Renderer + 'a,
+{
+    target(
+        Button::new(Container::new(icon).center(Length::Fill))
+            .on_press_maybe(on_press)
+            .style(move |theme, status| styled(theme, variant, None, status)),
+    )
+}
+
+/// Creates a toggleable icon button of the given [`Variant`].
+///
+/// The button is disabled when `on_press` is `None`.
+pub fn toggle<'a, Message, Renderer>(
+    variant: Variant,
+    selected: bool,
+    icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_press: Option<Message>,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: core::Renderer + 'a,
+{
+    target(
+        Button::new(Container::new(icon).center(Length::Fill))
+            .on_press_maybe(on_press)
+            .style(toggle_with(variant, selected)),
+    )
+}
+
+/// Centers the button inside its touch target.
+fn target<'a, Message, Renderer>(
+    button: Button<'a, Message, Theme, Renderer>,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: core::Renderer + 'a,
+{
+    Element::new(Target {
+        button: button.width(SIZE).height(SIZE).padding(0).into(),
+    })
+}
+
+/// The touch target of an icon button.
+///
+/// The cursor is moved onto the button while it is anywhere over the target,
+/// so the whole target hovers and presses the button.
+struct Target<'a, Message, Renderer> {
+    button: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<Message, Renderer> Target<'_, Message, Renderer> {
+    fn cursor(layout: Layout<'_>, cursor: mouse::Cursor) -> mouse::Cursor {
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+            return cursor;
+        };
+
+        let Some(button) = layout.children().next() else {
+            return cursor;
+        };
+
+        let bounds = button.bounds();
+
+        mouse::Cursor::Available(Point::new(
+            position.x.clamp(bounds.x, bounds.x + bounds.width - 1.0),
+            position.y.clamp(bounds.y, bounds.y + bounds.height - 1.0),
+        ))
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer>
+    for Target<'_, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.button)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.button));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(TARGET_SIZE), Length::Fixed(TARGET_SIZE))
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        _limits: &Limits,
+    ) -> layout::Node {
+        let inset = (TARGET_SIZE - SIZE) / 2.0;
+
+        let button = self.button.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            &Limits::new(Size::ZERO, Size::new(SIZE, SIZE)),
+        );
+
+        layout::Node::with_children(
+            Size::new(TARGET_SIZE, TARGET_SIZE),
+            vec![button.move_to(Point::new(inset, inset))],
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(button) = layout.children().next() {
+            self.button.as_widget_mut().operate(
+                &mut tree.children[0],
+                button,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let cursor = Self::cursor(layout, cursor);
+
+        if let Some(button) = layout.children().next() {
+            self.button.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                button,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let cursor = Self::cursor(layout, cursor);
+
+        layout
+            .children()
+            .next()
+            .map(|button| {
+                self.button.as_widget().mouse_interaction(
+                    &tree.children[0],
+                    button,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let cursor = Self::cursor(layout, cursor);
+
+        if let Some(button) = layout.children().next() {
+            self.button.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                button,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.button.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+pub fn standard(theme: &Theme, status: Status) -> Style {
+    styled(theme, Variant::Standard, None, status)
+}
+
+pub fn filled(theme: &Theme, status: Status) -> Style {
+    styled(theme, Variant::Filled, None, status)
+}
+
+pub fn tonal(theme: &Theme, status: Status) -> Style {
+    styled(theme, Variant::Tonal, None, status)
+}
+
+pub fn outlined(theme: &Theme, status: Status) -> Style {
+    styled(theme, Variant::Outlined, None, status)
+}
+
+/// The style of a toggleable icon button of the given [`Variant`].
+pub fn toggle_with(
+    variant: Variant,
+    selected: bool,
+) -> impl Fn(&Theme, Status) -> Style + Copy {
+    move |theme, status| styled(theme, variant, Some(selected), status)
+}
+
+/// Styles an icon button of the given [`Variant`].
+///
+/// `selected` is `None` for buttons that cannot be toggled.
+pub fn styled(
+    theme: &Theme,
+    variant: Variant,
+    selected: Option<bool>,
+    status: Status,
+) -> Style {
+    let colors = theme.colors();
+    let surface = colors.surface;
+
+    let (container, content) = match (variant, selected) {
+        (Variant::Standard, None | Some(false)) => {
+            (Color::TRANSPARENT, surface.on_surface_variant)
+        }
+        (Variant::Standard, Some(true)) => {
+            (Color::TRANSPARENT, colors.primary.color)
+        }
+        (Variant::Filled, None | Some(true)) => {
+            (colors.primary.color, colors.primary.on_primary)
+        }
+        (Variant::Filled, Some(false)) => {
+            (surface.surface_container.highest, colors.primary.color)
+        }
+        (Variant::Tonal, None | Some(true)) => (
+            colors.secondary.secondary_container,
+            colors.secondary.on_secondary_container,
+        ),
+        (Variant::Tonal, Some(false)) => (
+            surface.surface_container.highest,
+            surface.on_surface_variant,
+        ),
+        (Variant::Outlined, None | Some(false)) => {
+            (Color::TRANSPARENT, surface.on_surface_variant)
+        }
+        (Variant::Outlined, Some(true)) => (
+            colors.inverse.inverse_surface,
+            colors.inverse.inverse_on_surface,
+        ),
+    };
+
+    let outline = variant == Variant::Outlined && selected != Some(true);
+    let radius = theme.shapes().full.into();
+
+    if status == Status::Disabled {
+        let state_layer = theme.state_layer();
+
+        return Style {
+            background: (container.a != 0.0).then(|| {
+                Background::Color(
+                    state_layer.disabled_container(surface.on_surface),
+                )
+            }),
+            text_color: state_layer.disabled_content(surface.on_surface),
+            border: Border {
+                color: state_layer.disabled_container(surface.on_surface),
+                width: if outline { 1.0 } else { 0.0 },
+                radius,
+            },
+            snap: cfg!(feature = "crisp"),
+            ..Style::default()
+        };
+    }
+
+    let (background, text_color) = theme.state_layer().compose(
+        container,
+        content,
+        surface.on_surface,
+        status.into(),
+    );
+
+    Style {
+        background: (status != Status::Active || container.a != 0.0)
+            .then_some(Background::Color(background)),
+        text_color,
+        border: Border {
+            color: colors.outline.variant,
+            width: if outline { 1.0 } else { 0.0 },
+            radius,
+        },
+        snap: cfg!(feature = "crisp"),
+        ..Style::default()
+    }
+}
@@ -4,6 +4,7 @@ use std::sync::{Arc, LazyLock};
 
 #[cfg(feature = "system-theme")]
 use arc_swap::ArcSwap;
+use elevation::Elevation;
 use iced_widget::core::{
     Color, color,
     theme::{Base, Style},
@@ -16,26 +17,54 @@ use iced_widget::runtime::futures::{
 };
 #[cfg(feature = "system-theme")]
 use mundy::{Interest, Preferences};
-use utils::{lightness, mix};
+use overrides::{Component, Entry, Override, Overrides};
+use shape::Shapes;
+use sizing::Sizing;
+use typography::Typography;
+use utils::{State, StateLayer, lightness, mix};
 
 pub mod button;
+pub mod button_group;
+pub mod card;
 pub mod checkbox;
+pub mod chip;
+pub mod class;
+pub mod color_vision;
 pub mod combo_box;
 pub mod container;
 #[cfg(feature = "dialog")]
 pub mod dialog;
+pub mod elevation;
+pub mod fab;
+#[cfg(feature = "icons")]
+pub mod icon;
+pub mod icon_button;
+pub mod list;
 #[cfg(feature = "markdown")]
 pub mod markdown;
 pub mod menu;
+pub mod motion;
+pub mod navigation;
+pub mod navigation_bar;
+pub mod navigation_drawer;
+pub mod navigation_rail;
+pub mod overrides;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 #[cfg(feature = "qr_code")]
 pub mod qr_code;
 pub mod radio;
+#[cfg(feature = "roboto")]
+pub mod roboto;
 pub mod rule;
+pub mod scope;
 pub mod scrollable;
+pub mod segmented_button;
+pub mod shape;
+pub mod sizing;
 pub mod slider;
+pub mod split_button;
 #[cfg(feature = "svg")]
 pub mod svg;
 pub mod table;
@@ -43,6 +72,7 @@ pub mod text;
 pub mod text_editor;
 pub mod text_input;
 pub mod toggler;
+pub mod typography;
 pub mod utils;
 
 #[allow(clippy::cast_precision_loss)]
@@ -100,6 +130,12 @@ impl Theme {
             name: name.into(),
             is_dark: lightness(colorscheme.surface.color) <= 0.5,
             colorscheme,
+            typography: Typography::DEFAULT,
+            sizing: Sizing::DEFAULT,
+            state_layer: StateLayer::DEFAULT,
+            elevation: Elevation::DEFAULT,
+            shapes: Shapes::DEFAULT,
+            overrides: Overrides::DEFAULT,
         })
     }
 
@@ -111,6 +147,12 @@ impl Theme {
             name: Cow::Borrowed(name),
             is_dark: lightness(colorscheme.surface.color) <= 0.5,
             colorscheme,
+            typography: Typography::DEFAULT,
+            sizing: Sizing::DEFAULT,
+            state_layer: StateLayer::DEFAULT,
+            elevation: Elevation::DEFAULT,
+            shapes: Shapes::DEFAULT,
+            overrides: Overrides::DEFAULT,
         })
     }
 
@@ -144,6 +186,144 @@ impl Theme {
         }
     }
 
+    /// Returns the [`Typography`] of the [`Theme`].
+    pub fn typography(&self) -> Typography {
+        match self {
+            #[cfg(feature = "system-theme")]
+            Self::System => SYSTEM_THEME.load().typography(),
+            Self::Dark | Self::Light => Typography::DEFAULT,
+            Self::Custom(custom) => custom.typography,
+        }
+    }
+
+    /// Replaces the [`Typography`] of the [`Theme`].
+    ///
+    /// Built-in themes are turned into a [`Custom`] one.
+    pub fn with_typography(self, typography: Typography) -> Self {
+        Self::Custom(Custom {
+            typography,
+            ..self.into()
+        })
+    }
+
+    /// Returns the [`Shapes`] of the [`Theme`].
+    pub fn shapes(&self) -> Shapes {
+        match self {
+            #[cfg(feature = "system-theme")]
+            Self::System => SYSTEM_THEME.load().shapes(),
+            Self::Dark | Self::Light => Shapes::DEFAULT,
+            Self::Custom(custom) => custom.shapes,
+        }
+    }
+
+    /// Replaces the [`Shapes`] of the [`Theme`].
+    ///
+    /// Built-in themes are turned into a [`Custom`] one.
+    pub fn with_shapes(self, shapes: Shapes) -> Self {
+        Self::Custom(Custom {
+            shapes,
+            ..self.into()
+        })
+    }
+
+    /// Returns the [`Elevation`] of the [`Theme`].
+    pub fn elevation(&self) -> Elevation {
+        match self {
+            #[cfg(feature = "system-theme")]
+            Self::System => SYSTEM_THEME.load().elevation(),
+            Self::Dark | Self::Light => Elevation::DEFAULT,
+            Self::Custom(custom) => custom.elevation,
+        }
+    }
+
+    /// Replaces the [`Elevation`] of the [`Theme`].
+    ///
+    /// Built-in themes are turned into a [`Custom`] one.
+    pub fn with_elevation(self, elevation: Elevation) -> Self {
+        Self::Custom(Custom {
+            elevation,
+            ..self.into()
+        })
+    }
+
+    /// Returns the [`StateLayer`] of the [`Theme`].
+    pub fn state_layer(&self) -> StateLayer {
+        match self {
+            #[cfg(feature = "system-theme")]
+            Self::System => SYSTEM_THEME.load().state_layer(),
+            Self::Dark | Self::Light => StateLayer::DEFAULT,
+            Self::Custom(custom) => custom.state_layer,
+        }
+    }
+
+    /// Replaces the [`StateLayer`] of the [`Theme`].
+    ///
+    /// Built-in themes are turned into a [`Custom`] one.
+    pub fn with_state_layer(self, state_layer: StateLayer) -> Self {
+        Self::Custom(Custom {
+            state_layer,
+            ..self.into()
+        })
+    }
+
+    /// Returns the [`Sizing`] of the [`Theme`].
+    pub fn sizing(&self) -> Sizing {
+        match self {
+            #[cfg(feature = "system-theme")]
+            Self::System => SYSTEM_THEME.load().sizing(),
+            Self::Dark | Self::Light => Sizing::DEFAULT,
+            Self::Custom(custom) => custom.sizing,
+        }
+    }
+
+    /// Replaces the [`Sizing`] of the [`Theme`].
+    ///
+    /// Built-in themes are turned into a [`Custom`] one.
+    pub fn with_sizing(self, sizing: Sizing) -> Self {
+        Self::Custom(Custom {
+            sizing,
+            ..self.into()
+        })
+    }
+
+    /// Returns the entries of the [`Overrides`] of the [`Theme`].
+    ///
+    /// The entries of a [`Custom`] theme are borrowed.
+    pub fn overrides(&self) -> Cow<'_, [Entry]> {
+        match self {
+            #[cfg(feature = "system-theme")]
+            Self::System => SYSTEM_THEME.load().overrides().into_owned().into(),
+            Self::Dark | Self::Light => Cow::Borrowed(&[]),
+            Self::Custom(custom) => Cow::Borrowed(&custom.overrides.entries),
+        }
+    }
+
+    /// Returns the [`Override`] of the given component in the given state.
+    pub fn component_override(
+        &self,
+        component: Component,
+        status: State,
+    ) -> Override {
+        match self {
+            #[cfg(feature = "system-theme")]
+            Self::System => {
+                SYSTEM_THEME.load().component_override(component, status)
+            }
+            Self::Dark | Self::Light => Override::NONE,
+            Self::Custom(custom) => custom.overrides.get(component, status),
+        }
+    }
+
+    /// Replaces the [`Overrides`] of the [`Theme`].
+    ///
+    /// Built-in themes are turned into a [`Custom`] one.
+    pub fn with_overrides(self, overrides: Overrides) -> Self {
+        Self::Custom(Custom {
+            overrides,
+            ..self.into()
+        })
+    }
+
     /// A subscription that responds to the user's theme preference changing and returns the
     /// corresponding [`Theme`] variant.
     #[cfg(feature = "system-theme")]
@@ -285,6 +465,14 @@ impl iced_anim::Animate for Theme {
             name: "Animating Theme".into(),
             is_dark: self.is_dark(),
             colorscheme,
+            typography: self.typography(),
+            sizing: self.sizing(),
+            state_layer: self.state_layer(),
+            elevation: self.elevation(),
+            shapes: self.shapes(),
+            overrides: Overrides {
+                entries: self.overrides().into_owned(),
+            },
         });
     }
 
@@ -299,6 +487,14 @@ impl iced_anim::Animate for Theme {
             name: "Animating Theme".into(),
             is_dark: self.is_dark(),
             colorscheme,
+            typography: self.typography(),
+            sizing: self.sizing(),
+            state_layer: self.state_layer(),
+            elevation: self.elevation(),
+            shapes: self.shapes(),
+            overrides: Overrides {
+                entries: self.overrides().into_owned(),
+            },
         });
     }
 }
@@ -314,6 +510,24 @@ pub struct Custom {
     /// The [`Theme`]'s [`ColorScheme`].
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub colorscheme: ColorScheme,
+    /// The [`Theme`]'s [`Typography`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub typography: Typography,
+    /// The [`Theme`]'s [`Sizing`] and density.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sizing: Sizing,
+    /// The [`Theme`]'s [`StateLayer`] opacities.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub state_layer: StateLayer,
+    /// The [`Theme`]'s [`Elevation`] levels.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub elevation: Elevation,
+    /// The [`Theme`]'s [`Shapes`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub shapes: Shapes,
+    /// The [`Theme`]'s component [`Overrides`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub overrides: Overrides,
 }
 
 impl From<Custom> for Theme {
@@ -330,6 +544,14 @@ impl From<Theme> for Custom {
                 name: theme.name(),
                 is_dark: theme.is_dark(),
                 colorscheme: theme.colors(),
+                typography: theme.typography(),
+                sizing: theme.sizing(),
+                state_layer: theme.state_layer(),
+                elevation: theme.elevation(),
+                shapes: theme.shapes(),
+                overrides: Overrides {
+                    entries: theme.overrides().into_owned(),
+                },
             },
         }
     }
@@ -341,6 +563,12 @@ impl Clone for Custom {
             name: self.name.clone(),
             is_dark: self.is_dark,
             colorscheme: self.colorscheme,
+            typography: self.typography,
+            sizing: self.sizing,
+            state_layer: self.state_layer,
+            elevation: self.elevation,
+            shapes: self.shapes,
+            overrides: self.overrides.clone(),
         }
     }
 
@@ -348,6 +576,12 @@ impl Clone for Custom {
         self.name.clone_from(&source.name);
         self.is_dark = source.is_dark;
         self.colorscheme = source.colorscheme;
+        self.typography = source.typography;
+        self.sizing = source.sizing;
+        self.state_layer = source.state_layer;
+        self.elevation = source.elevation;
+        self.shapes = source.shapes;
+        self.overrides.clone_from(&source.overrides);
     }
 }
 
@@ -484,6 +718,61 @@ impl ColorScheme {
         shadow: color!(0x000000),
         scrim: from_argb!(0x4d000000),
     };
+
+    /// Applies the given function to every color of the [`ColorScheme`].
+    pub fn map(&self, f: impl Fn(Color) -> Color) -> Self {
+        Self {
+            primary: Primary {
+                color: f(self.primary.color),
+                on_primary: f(self.primary.on_primary),
+                primary_container: f(self.primary.primary_container),
+                on_primary_container: f(self.primary.on_primary_container),
+            },
+            secondary: Secondary {
+                color: f(self.secondary.color),
+                on_secondary: f(self.secondary.on_secondary),
+                secondary_container: f(self.secondary.secondary_container),
+                on_secondary_container: f(self
+                    .secondary
+                    .on_secondary_container),
+            },
+            tertiary: Tertiary {
+                color: f(self.tertiary.color),
+                on_tertiary: f(self.tertiary.on_tertiary),
+                tertiary_container: f(self.tertiary.tertiary_container),
+                on_tertiary_container: f(self.tertiary.on_tertiary_container),
+            },
+            error: Error {
+                color: f(self.error.color),
+                on_error: f(self.error.on_error),
+                error_container: f(self.error.error_container),
+                on_error_container: f(self.error.on_error_container),
+            },
+            surface: Surface {
+                color: f(self.surface.color),
+                on_surface: f(self.surface.on_surface),
+                on_surface_variant: f(self.surface.on_surface_variant),
+                surface_container: SurfaceContainer {
+                    lowest: f(self.surface.surface_container.lowest),
+                    low: f(self.surface.surface_container.low),
+                    base: f(self.surface.surface_container.base),
+                    high: f(self.surface.surface_container.high),
+                    highest: f(self.surface.surface_container.highest),
+                },
+            },
+            inverse: Inverse {
+                inverse_surface: f(self.inverse.inverse_surface),
+                inverse_on_surface: f(self.inverse.inverse_on_surface),
+                inverse_primary: f(self.inverse.inverse_primary),
+            },
+            outline: Outline {
+                color: f(self.outline.color),
+                variant: f(self.outline.variant),
+            },
+            shadow: f(self.shadow),
+            scrim: f(self.scrim),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -593,6 +882,66 @@ pub struct Outline {
     pub variant: Color,
 }
 
+/// A color role that a component can be styled with.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Role {
+    #[default]
+    Primary,
+    Secondary,
+    Tertiary,
+    Error,
+    /// A custom group of colors, e.g. for a brand or "success" color.
+    Custom(ColorGroup),
+}
+
+/// The colors of a [`Role`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorGroup {
+    #[cfg_attr(feature = "serde", serde(with = "color_serde"))]
+    pub color: Color,
+    #[cfg_attr(feature = "serde", serde(with = "color_serde"))]
+    pub on_color: Color,
+    #[cfg_attr(feature = "serde", serde(with = "color_serde"))]
+    pub container: Color,
+    #[cfg_attr(feature = "serde", serde(with = "color_serde"))]
+    pub on_container: Color,
+}
+
+impl ColorScheme {
+    /// Returns the [`ColorGroup`] of the given [`Role`].
+    pub fn role(&self, role: Role) -> ColorGroup {
+        match role {
+            Role::Primary => ColorGroup {
+                color: self.primary.color,
+                on_color: self.primary.on_primary,
+                container: self.primary.primary_container,
+                on_container: self.primary.on_primary_container,
+            },
+            Role::Secondary => ColorGroup {
+                color: self.secondary.color,
+                on_color: self.secondary.on_secondary,
+                container: self.secondary.secondary_container,
+                on_container: self.secondary.on_secondary_container,
+            },
+            Role::Tertiary => ColorGroup {
+                color: self.tertiary.color,
+                on_color: self.tertiary.on_tertiary,
+                container: self.tertiary.tertiary_container,
+                on_container: self.tertiary.on_tertiary_container,
+            },
+            Role::Error => ColorGroup {
+                color: self.error.color,
+                on_color: self.error.on_error,
+                container: self.error.error_container,
+                on_container: self.error.on_error_container,
+            },
+            Role::Custom(group) => group,
+        }
+    }
+}
+
 #[cfg(feature = "serde")]
 mod color_serde {
     use iced_widget::core::Color;
@@ -600,6 +949,35 @@ mod color_serde {
 
     use super::utils::{color_to_argb, parse_argb};
 
+    pub mod option {
+        use iced_widget::core::Color;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn deserialize<'de, D>(
+            deserializer: D,
+        ) -> Result<Option<Color>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Option::<String>::deserialize(deserializer)
+                .map(|hex| hex.and_then(|hex| super::parse_argb(&hex)))
+        }
+
+        #[allow(clippy::ref_option)]
+        pub fn serialize<S>(
+            color: &Option<Color>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match color {
+                Some(color) => super::serialize(color, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+    }
+
     pub fn deserialize<'de, D>(deserializer: D) -> Result<Color, D::Error>
     where
         D: Deserializer<'de>,
@@ -0,0 +1,803 @@
+//! [Lists](https://m3.material.io/components/lists/overview).
+//!
+//! A [`List`] stacks [`ListItem`]s vertically, optionally separated by
+//! dividers drawn with a [`rule`] class. Once it has been
+//! clicked, the arrow keys, <kbd>Home</kbd> and <kbd>End</kbd> move the
+//! focus across its enabled items, and <kbd>Enter</kbd> or <kbd>Space</kbd>
+//! presses the focused one.
+//!
+//! A [`ListItem`] has one, two or three lines of text, depending on whether
+//! it has an overline and supporting text, plus an optional leading and
+//! trailing slot.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced_material::{Theme, list, rule};
+//! use iced_widget::{checkbox, text, toggler};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     OpenAccount,
+//!     ToggleWifi(bool),
+//!     ToggleSync(bool),
+//! }
+//!
+//! # fn view<'a>(theme: &Theme, wifi: bool, sync: bool) -> iced_widget::core::Element<'a, Message, Theme, iced_widget::Renderer> {
+//! list::List::new([
+//!     list::item(theme, "Account")
+//!         .supporting("Ada Lovelace")
+//!         .avatar(text("A"))
+//!         .on_press(Message::OpenAccount),
+//!     list::item(theme, "Wi-Fi")
+//!         .trailing(toggler(wifi).on_toggle(Message::ToggleWifi))
+//!         .on_press(Message::ToggleWifi(!wifi)),
+//!     list::item(theme, "Sync")
+//!         .overline("Backup")
+//!         .supporting("Keep your files up to date")
+//!         .control(checkbox("", sync).on_toggle(Message::ToggleSync))
+//!         .on_press(Message::ToggleSync(!sync)),
+//! ])
+//! .dividers(rule::Class::FullWidth)
+//! .into()
+//! # }
+//! ```
+use std::fmt;
+
+use iced_widget::button::{Status, Style};
+use iced_widget::core::keyboard::{self, key};
+use iced_widget::core::layout::{self, Layout, Limits};
+use iced_widget::core::text::IntoFragment;
+use iced_widget::core::widget::{Operation, Tree, tree};
+use iced_widget::core::{
+    self, Alignment, Background, Clipboard, Color, Element, Event, Length,
+    Padding, Point, Rectangle, Shell, Size, Vector, Widget, border, mouse,
+    overlay, renderer, touch,
+};
+use iced_widget::{Button, Column, Container, Row, Text, text};
+
+use crate::Theme;
+use crate::rule;
+use crate::sizing::Sizing;
+use crate::typography::Typography;
+
+/// The size of a leading or trailing icon, in logical pixels.
+pub const ICON_SIZE: f32 = 24.0;
+
+/// The size of a leading avatar, in logical pixels.
+pub const AVATAR_SIZE: f32 = 40.0;
+
+/// The size of a leading image, in logical pixels.
+pub const IMAGE_SIZE: f32 = 56.0;
+
+/// The kind of content in the leading slot of a list item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Leading {
+    Icon,
+    Avatar,
+    Image,
+    Control,
+}
+
+impl Leading {
+    fn size(self) -> Option<f32> {
+        match self {
+            Self::Icon => Some(ICON_SIZE),
+            Self::Avatar => Some(AVATAR_SIZE),
+            Self::Image => Some(IMAGE_SIZE),
+            Self::Control => None,
+        }
+    }
+}
+
+/// An item of a [`List`].
+pub struct ListItem<'a, Message, Renderer = iced_widget::Renderer>
+where
+    Renderer: core::text::Renderer,
+{
+    typography: Typography,
+    sizing: Sizing,
+    headline: Text<'a, Theme, Renderer>,
+    supporting: Option<Text<'a, Theme, Renderer>>,
+    overline: Option<Text<'a, Theme, Renderer>>,
+    leading: Option<(Leading, Element<'a, Message, Theme, Renderer>)>,
+    trailing: Option<Element<'a, Message, Theme, Renderer>>,
+    trailing_text: Option<Text<'a, Theme, Renderer>>,
+    selected: bool,
+    on_press: Option<Message>,
+}
+
+/// Creates a [`ListItem`] with the given headline, using the typography and
+/// density of the [`Theme`].
+pub fn item<'a, Message, Renderer>(
+    theme: &Theme,
+    headline: impl IntoFragment<'a>,
+) -> ListItem<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+{
+    let typography = theme.typography();
+
+    ListItem {
+        typography,
+        sizing: theme.sizing(),
+        headline: typography.body_large.apply(Text::new(headline)),
+        supporting: None,
+        overline: None,
+        leading: None,
+        trailing: None,
+        trailing_text: None,
+        selected: false,
+        on_press: None,
+    }
+}
+
+impl<'a, Message, Renderer> ListItem<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+{
+    /// Sets the supporting text of the [`ListItem`], shown below the
+    /// headline.
+    pub fn supporting(self, supporting: impl IntoFragment<'a>) -> Self {
+        Self {
+            supporting: Some(
+                self.typography.body_medium.apply(Text::new(supporting)),
+            ),
+            ..self
+        }
+    }
+
+    /// Sets the overline of the [`ListItem`], shown above the headline.
+    pub fn overline(self, overline: impl IntoFragment<'a>) -> Self {
+        Self {
+            overline: Some(
+                self.typography.label_small.apply(Text::new(overline)),
+            ),
+            ..self
+        }
+    }
+
+    /// Sets the leading icon of the [`ListItem`].
+    pub fn icon(
+        self,
+        icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.leading(Leading::Icon, icon)
+    }
+
+    /// Sets the leading avatar of the [`ListItem`], usually an image of a
+    /// person.
+    pub fn avatar(
+        self,
+        avatar: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.leading(Leading::Avatar, avatar)
+    }
+
+    /// Sets the leading image of the [`ListItem`].
+    pub fn image(
+        self,
+        image: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.leading(Leading::Image, image)
+    }
+
+    /// Sets the leading control of the [`ListItem`], like a checkbox or a
+    /// radio button.
+    pub fn control(
+        self,
+        control: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.leading(Leading::Control, control)
+    }
+
+    fn leading(
+        self,
+        kind: Leading,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            leading: Some((kind, content.into())),
+            ..self
+        }
+    }
+
+    /// Sets the trailing content of the [`ListItem`], like an icon or a
+    /// switch.
+    pub fn trailing(
+        self,
+        trailing: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            trailing: Some(trailing.into()),
+            trailing_text: None,
+            ..self
+        }
+    }
+
+    /// Sets the trailing supporting text of the [`ListItem`], like a
+    /// shortcut or a count.
+    pub fn trailing_text(self, trailing: impl IntoFragment<'a>) -> Self {
+        Self {
+            trailing: None,
+            trailing_text: Some(
+                self.typography.label_small.apply(Text::new(trailing)),
+            ),
+            ..self
+        }
+    }
+
+    /// Sets whether the [`ListItem`] is selected.
+    pub fn selected(self, selected: bool) -> Self {
+        Self { selected, ..self }
+    }
+
+    /// Sets the message produced when the [`ListItem`] is pressed.
+    ///
+    /// The [`ListItem`] is disabled if it has no message.
+    pub fn on_press(self, on_press: Message) -> Self {
+        Self {
+            on_press: Some(on_press),
+            ..self
+        }
+    }
+
+    /// Sets the message produced when the [`ListItem`] is pressed, if any.
+    pub fn on_press_maybe(self, on_press: Option<Message>) -> Self {
+        Self { on_press, ..self }
+    }
+
+    /// The number of lines of text of the [`ListItem`].
+    pub fn lines(&self) -> usize {
+        1 + usize::from(self.overline.is_some())
+            + usize::from(self.supporting.is_some())
+    }
+
+    /// The height of the [`ListItem`].
+    ///
+    /// Two- and three-line items are 16 and 32 pixels taller than the
+    /// one-line height of the [`Sizing`].
+    pub fn height(&self) -> f32 {
+        self.sizing.list_item_height()
+            + match self.lines() {
+                1 => 0.0,
+                2 => 16.0,
+                _ => 32.0,
+            }
+    }
+}
+
+impl<Message, Renderer> fmt::Debug for ListItem<'_, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListItem")
+            .field("sizing", &self.sizing)
+            .field("leading", &self.leading.as_ref().map(|(kind, _)| kind))
+            .field("selected", &self.selected)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Message, Renderer> From<ListItem<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: core::text::Renderer<Font = core::Font> + 'a,
+{
+    fn from(item: ListItem<'a, Message, Renderer>) -> Self {
+        let height = item.height();
+        let is_three_line = item.lines() > 2;
+        let is_enabled = item.on_press.is_some();
+
+        let secondary = supporting_with(is_enabled);
+
+        let text = Column::new()
+            .push(item.overline.map(|overline| overline.style(secondary)))
+            .push(item.headline)
+            .push(
+                item.supporting
+                    .map(|supporting| supporting.style(secondary)),
+            )
+            .width(Length::Fill);
+
+        let leading = item.leading.map(|(kind, content)| {
+            let container = Container::new(content);
+
+            match kind.size() {
+                Some(size) => container
+                    .center(size)
+                    .clip(matches!(kind, Leading::Avatar | Leading::Image)),
+                None => container,
+            }
+        });
+
+        let trailing = item.trailing.or_else(|| {
+            item.trailing_text.map(|text| text.style(secondary).into())
+        });
+
+        let content = Row::new()
+            .push(leading)
+            .push(text)
+            .push(trailing)
+            .spacing(16)
+            .height(Length::Fill)
+            .align_y(if is_three_line {
+                Alignment::Start
+            } else {
+                Alignment::Center
+            });
+
+        Button::new(content)
+            .width(Length::Fill)
+            .height(height)
+            .padding(Padding::from([if is_three_line { 12 } else { 8 }, 16]))
+            .on_press_maybe(item.on_press)
+            .style(styled_with(item.selected))
+            .into()
+    }
+}
+
+/// A vertical list of [`ListItem`]s with keyboard navigation.
+pub struct List<'a, Message, Renderer = iced_widget::Renderer> {
+    items: Vec<Element<'a, Message, Theme, Renderer>>,
+    on_press: Vec<Option<Message>>,
+    dividers: Option<rule::Class<'a>>,
+    width: Length,
+}
+
+impl<'a, Message, Renderer> List<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: core::text::Renderer<Font = core::Font> + 'a,
+{
+    /// Creates a [`List`] with the given items.
+    pub fn new(
+        items: impl IntoIterator<Item = ListItem<'a, Message, Renderer>>,
+    ) -> Self {
+        items.into_iter().fold(
+            Self {
+                items: Vec::new(),
+                on_press: Vec::new(),
+                dividers: None,
+                width: Length::Fill,
+            },
+            Self::push,
+        )
+    }
+
+    /// Adds an item to the [`List`].
+    pub fn push(mut self, item: ListItem<'a, Message, Renderer>) -> Self {
+        self.on_press.push(item.on_press.clone());
+        self.items.push(item.into());
+        self
+    }
+
+    /// Separates the items of the [`List`] with dividers of the given
+    /// [`rule`] class.
+    pub fn dividers(self, class: impl Into<rule::Class<'a>>) -> Self {
+        Self {
+            dividers: Some(class.into()),
+            ..self
+        }
+    }
+
+    /// Sets the width of the [`List`].
+    pub fn width(self, width: impl Into<Length>) -> Self {
+        Self {
+            width: width.into(),
+            ..self
+        }
+    }
+}
+
+impl<Message, Renderer> fmt::Debug for List<'_, Message, Renderer> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("List")
+            .field("items", &self.items.len())
+            .field("dividers", &self.dividers)
+            .field("width", &self.width)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    is_focused: bool,
+    focused: Option<usize>,
+}
+
+impl<Message, Renderer> List<'_, Message, Renderer> {
+    fn divider_height(&self) -> f32 {
+        if self.dividers.is_some() { 1.0 } else { 0.0 }
+    }
+
+    /// Moves the focus from the given item to the next enabled one in the
+    /// given direction, wrapping around.
+    fn next(&self, from: Option<usize>, forward: bool) -> Option<usize> {
+        let len = self.on_press.len();
+
+        (1..=len)
+            .map(|step| match (from, forward) {
+                (Some(index), true) => (index + step) % len,
+                (Some(index), false) => (index + len - step) % len,
+                (None, true) => step - 1,
+                (None, false) => len - step,
+            })
+            .find(|index| self.on_press[*index].is_some())
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer>
+    for List<'_, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.items.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.items);
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.focused.is_some_and(|index| index >= self.items.len()) {
+            state.focused = None;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+        let divider = self.divider_height();
+
+        let mut y = 0.0;
+        let mut width: f32 = 0.0;
+
+        let nodes = self
+            .items
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(item, tree)| {
+                let node = item
+                    .as_widget_mut()
+                    .layout(tree, renderer, &limits)
+                    .move_to(Point::new(0.0, y));
+
+                let size = node.size();
+                y += size.height + divider;
+                width = width.max(size.width);
+
+                node
+            })
+            .collect();
+
+        let height = (y - divider).max(0.0);
+
+        layout::Node::with_children(
+            limits.resolve(
+                self.width,
+                Length::Shrink,
+                Size::new(width, height),
+            ),
+            nodes,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.items
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((item, state), layout)| {
+                    item.as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((item, tree), layout) in self
+            .items
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            item.as_widget_mut().update(
+                tree, event, layout, cursor, renderer, clipboard, shell,
+                viewport,
+            );
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let is_focused = cursor.is_over(layout.bounds());
+
+                if is_focused != state.is_focused || state.focused.is_some() {
+                    state.is_focused = is_focused;
+                    state.focused = None;
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) if state.is_focused => {
+                let focused = match named {
+                    key::Named::ArrowDown => self.next(state.focused, true),
+                    key::Named::ArrowUp => self.next(state.focused, false),
+                    key::Named::Home => self.next(None, true),
+                    key::Named::End => self.next(None, false),
+                    key::Named::Enter | key::Named::Space => {
+                        if let Some(on_press) = state
+                            .focused
+                            .and_then(|index| self.on_press[index].clone())
+                        {
+                            shell.publish(on_press);
+                            shell.capture_event();
+                        }
+
+                        return;
+                    }
+                    key::Named::Escape if state.focused.is_some() => None,
+                    _ => return,
+                };
+
+                state.focused = focused;
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.items
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((item, tree), layout)| {
+                item.as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let divider = self.dividers.as_ref().map(|class| {
+            <Theme as iced_widget::rule::Catalog>::style(theme, class)
+        });
+
+        for (index, ((item, tree), layout)) in self
+            .items
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            item.as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+
+            let bounds = layout.bounds();
+
+            if state.is_focused && state.focused == Some(index) {
+                let surface = theme.colors().surface;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        snap: cfg!(feature = "crisp"),
+                        ..renderer::Quad::default()
+                    },
+                    Background::Color(Color {
+                        a: theme.state_layer().focus,
+                        ..surface.on_surface
+                    }),
+                );
+            }
+
+            if let Some(divider) =
+                divider.filter(|_| index + 1 < self.items.len())
+            {
+                let (offset, width) = divider.fill_mode.fill(bounds.width);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + offset,
+                            y: bounds.y + bounds.height,
+                            width,
+                            height: 1.0,
+                        },
+                        border: border::rounded(divider.radius),
+                        snap: divider.snap,
+                        ..renderer::Quad::default()
+                    },
+                    Background::Color(divider.color),
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.items,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<List<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(list: List<'a, Message, Renderer>) -> Self {
+        Self::new(list)
+    }
+}
+
+/// A list item, using the secondary container color while selected.
+pub fn styled_with(
+    is_selected: bool,
+) -> impl Fn(&Theme, Status) -> Style + Copy {
+    move |theme, status| {
+        let colors = theme.colors();
+        let surface = colors.surface;
+
+        let (container, content) = if is_selected {
+            (
+                colors.secondary.secondary_container,
+                colors.secondary.on_secondary_container,
+            )
+        } else {
+            (Color::TRANSPARENT, surface.on_surface)
+        };
+
+        let (background, text_color) = theme.state_layer().compose(
+            container,
+            content,
+            surface.on_surface,
+            status.into(),
+        );
+
+        Style {
+            background: Some(Background::Color(background)),
+            text_color,
+            snap: cfg!(feature = "crisp"),
+            ..Style::default()
+        }
+    }
+}
+
+/// The overline, supporting and trailing text of a list item.
+pub fn supporting_with(
+    is_enabled: bool,
+) -> impl Fn(&Theme) -> text::Style + Copy {
+    move |theme| {
+        let surface = theme.colors().surface;
+
+        text::Style {
+            color: Some(if is_enabled {
+                surface.on_surface_variant
+            } else {
+                theme.state_layer().disabled_content(surface.on_surface)
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{List, item};
+    use crate::Theme;
+
+    fn list(enabled: &[bool]) -> List<'static, usize, ()> {
+        List::new(enabled.iter().enumerate().map(|(index, is_enabled)| {
+            item(&Theme::Light, "Item")
+                .on_press_maybe(is_enabled.then_some(index))
+        }))
+    }
+
+    #[test]
+    fn next_wraps_around() {
+        let list = list(&[true, true, true]);
+
+        assert_eq!(list.next(Some(0), true), Some(1));
+        assert_eq!(list.next(Some(2), true), Some(0));
+        assert_eq!(list.next(Some(0), false), Some(2));
+    }
+
+    #[test]
+    fn next_skips_disabled_items() {
+        let list = list(&[true, false, false, true]);
+
+        assert_eq!(list.next(Some(0), true), Some(3));
+        assert_eq!(list.next(Some(3), false), Some(0));
+        assert_eq!(list.next(Some(3), true), Some(0));
+    }
+
+    #[test]
+    fn next_from_the_ends() {
+        let list = list(&[false, true, true, false]);
+
+        assert_eq!(list.next(None, true), Some(1));
+        assert_eq!(list.next(None, false), Some(2));
+    }
+
+    #[test]
+    fn next_without_enabled_items() {
+        assert_eq!(list(&[false, false]).next(None, true), None);
+        assert_eq!(list(&[]).next(Some(0), false), None);
+    }
+}
@@ -5,6 +5,6 @@ use super::{Theme, container};
 impl Catalog for Theme {
     fn code_block<'a>() -> <Self as iced_widget::container::Catalog>::Class<'a>
     {
-        Box::new(container::surface_container_highest)
+        container::Class::SurfaceContainerHighest
     }
 }
@@ -2,32 +2,48 @@ use iced_widget::core::{Background, border};
 use iced_widget::overlay::menu::{Catalog, Style, StyleFn};
 
 use super::Theme;
-use crate::utils::{HOVERED_LAYER_OPACITY, mix};
+use crate::class::style_class;
+use crate::overrides::Component;
+use crate::utils::State;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> <Self as Catalog>::Class<'a> {
-        Box::new(default)
+        Class::Default
     }
 
     fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style {
-        class(self)
+        class.style(self)
+    }
+}
+
+style_class! {
+    /// The named styles of a menu.
+    () -> Style {
+        Default => default,
     }
 }
 
 pub fn default(theme: &Theme) -> Style {
     let colors = theme.colors().surface;
+    let overrides = theme.component_override(Component::Menu, State::Enabled);
 
     Style {
-        border: border::rounded(4),
+        border: overrides.border(border::rounded(theme.shapes().extra_small)),
         background: Background::Color(colors.surface_container.base),
         text_color: colors.on_surface,
-        selected_background: Background::Color(mix(
-            colors.surface_container.base,
-            colors.on_surface,
-            HOVERED_LAYER_OPACITY,
-        )),
+        selected_background: Background::Color(
+            theme
+                .state_layer()
+                .compose(
+                    colors.surface_container.base,
+                    colors.on_surface,
+                    colors.on_surface,
+                    State::Hovered,
+                )
+                .0,
+        ),
         selected_text_color: colors.on_surface,
     }
 }
@@ -0,0 +1,219 @@
+//! Material 3 [motion](https://m3.material.io/styles/motion/overview) tokens.
+//!
+//! The [`Spring`]s convert into `iced_anim::SpringMotion` when the
+//! `animate` feature is enabled.
+//!
+//! The easing curves don't convert into an `iced_anim` easing; sample them
+//! with [`CubicBezier::ease`] instead.
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+/// The duration tokens.
+pub mod duration {
+    use std::time::Duration;
+
+    pub const SHORT1: Duration = Duration::from_millis(50);
+    pub const SHORT2: Duration = Duration::from_millis(100);
+    pub const SHORT3: Duration = Duration::from_millis(150);
+    pub const SHORT4: Duration = Duration::from_millis(200);
+    pub const MEDIUM1: Duration = Duration::from_millis(250);
+    pub const MEDIUM2: Duration = Duration::from_millis(300);
+    pub const MEDIUM3: Duration = Duration::from_millis(350);
+    pub const MEDIUM4: Duration = Duration::from_millis(400);
+    pub const LONG1: Duration = Duration::from_millis(450);
+    pub const LONG2: Duration = Duration::from_millis(500);
+    pub const LONG3: Duration = Duration::from_millis(550);
+    pub const LONG4: Duration = Duration::from_millis(600);
+    pub const EXTRA_LONG1: Duration = Duration::from_millis(700);
+    pub const EXTRA_LONG2: Duration = Duration::from_millis(800);
+    pub const EXTRA_LONG3: Duration = Duration::from_millis(900);
+    pub const EXTRA_LONG4: Duration = Duration::from_millis(1000);
+}
+
+/// The easing tokens.
+pub mod easing {
+    use super::CubicBezier;
+
+    pub const EMPHASIZED: CubicBezier = CubicBezier::new(0.2, 0.0, 0.0, 1.0);
+    pub const EMPHASIZED_DECELERATE: CubicBezier =
+        CubicBezier::new(0.05, 0.7, 0.1, 1.0);
+    pub const EMPHASIZED_ACCELERATE: CubicBezier =
+        CubicBezier::new(0.3, 0.0, 0.8, 0.15);
+    pub const STANDARD: CubicBezier = CubicBezier::new(0.2, 0.0, 0.0, 1.0);
+    pub const STANDARD_DECELERATE: CubicBezier =
+        CubicBezier::new(0.0, 0.0, 0.0, 1.0);
+    pub const STANDARD_ACCELERATE: CubicBezier =
+        CubicBezier::new(0.3, 0.0, 1.0, 1.0);
+    pub const LEGACY: CubicBezier = CubicBezier::new(0.4, 0.0, 0.2, 1.0);
+    pub const LEGACY_DECELERATE: CubicBezier =
+        CubicBezier::new(0.0, 0.0, 0.2, 1.0);
+    pub const LEGACY_ACCELERATE: CubicBezier =
+        CubicBezier::new(0.4, 0.0, 1.0, 1.0);
+    pub const LINEAR: CubicBezier = CubicBezier::new(0.0, 0.0, 1.0, 1.0);
+}
+
+/// A cubic Bézier easing curve from `(0, 0)` to `(1, 1)`, defined by its two
+/// control points like CSS' `cubic-bezier()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier {
+    pub x1: f32,
+    pub y1: f32,
+    pub x2: f32,
+    pub y2: f32,
+}
+
+impl CubicBezier {
+    pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self { x1, y1, x2, y2 }
+    }
+
+    /// Returns the eased progress for the given linear progress in `[0, 1]`.
+    pub fn ease(&self, progress: f32) -> f32 {
+        let progress = progress.clamp(0.0, 1.0);
+
+        if progress == 0.0 || progress == 1.0 {
+            return progress;
+        }
+
+        let t = self.solve_x(progress);
+
+        Self::sample(self.y1, self.y2, t)
+    }
+
+    fn sample(p1: f32, p2: f32, t: f32) -> f32 {
+        let u = 1.0 - t;
+
+        3.0 * u * u * t * p1 + 3.0 * u * t * t * p2 + t * t * t
+    }
+
+    fn sample_derivative(p1: f32, p2: f32, t: f32) -> f32 {
+        let u = 1.0 - t;
+
+        3.0 * u * u * p1 + 6.0 * u * t * (p2 - p1) + 3.0 * t * t * (1.0 - p2)
+    }
+
+    /// Finds the curve parameter for the given `x`, using Newton's method
+    /// with a bisection fallback.
+    fn solve_x(&self, x: f32) -> f32 {
+        const EPSILON: f32 = 1e-6;
+
+        let mut t = x;
+
+        for _ in 0..8 {
+            let error = Self::sample(self.x1, self.x2, t) - x;
+
+            if error.abs() < EPSILON {
+                return t;
+            }
+
+            let derivative = Self::sample_derivative(self.x1, self.x2, t);
+
+            if derivative.abs() < EPSILON {
+                break;
+            }
+
+            t -= error / derivative;
+        }
+
+        let (mut low, mut high) = (0.0, 1.0);
+        t = x;
+
+        while high - low > EPSILON {
+            if Self::sample(self.x1, self.x2, t) < x {
+                low = t;
+            } else {
+                high = t;
+            }
+
+            t = (low + high) / 2.0;
+        }
+
+        t
+    }
+}
+
+/// A physics-based spring, defined by its stiffness and damping ratio.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    pub stiffness: f32,
+    /// `1.0` is critically damped, lower values overshoot.
+    pub damping_ratio: f32,
+}
+
+impl Spring {
+    pub const fn new(stiffness: f32, damping_ratio: f32) -> Self {
+        Self {
+            stiffness,
+            damping_ratio,
+        }
+    }
+
+    /// The time a single oscillation of the undamped spring takes, assuming
+    /// a unit mass.
+    pub fn response(&self) -> Duration {
+        Duration::from_secs_f32(TAU / self.stiffness.sqrt())
+    }
+}
+
+#[cfg(feature = "animate")]
+impl From<Spring> for iced_anim::SpringMotion {
+    fn from(spring: Spring) -> Self {
+        Self::Custom {
+            response: spring.response(),
+            damping: spring.damping_ratio,
+        }
+    }
+}
+
+/// A set of spring tokens.
+///
+/// Spatial springs move things around and may overshoot, while effects
+/// springs animate properties like color and opacity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Springs {
+    pub spatial_fast: Spring,
+    pub spatial_default: Spring,
+    pub spatial_slow: Spring,
+    pub effects_fast: Spring,
+    pub effects_default: Spring,
+    pub effects_slow: Spring,
+}
+
+impl Springs {
+    /// The springs of the M3 Expressive expressive motion scheme.
+    pub const EXPRESSIVE: Self = Self {
+        spatial_fast: Spring::new(800.0, 0.6),
+        spatial_default: Spring::new(380.0, 0.8),
+        spatial_slow: Spring::new(200.0, 0.8),
+        effects_fast: Spring::new(3800.0, 1.0),
+        effects_default: Spring::new(1600.0, 1.0),
+        effects_slow: Spring::new(800.0, 1.0),
+    };
+
+    /// The springs of the M3 Expressive standard motion scheme.
+    pub const STANDARD: Self = Self {
+        spatial_fast: Spring::new(1400.0, 0.9),
+        spatial_default: Spring::new(700.0, 0.9),
+        spatial_slow: Spring::new(300.0, 0.9),
+        effects_fast: Spring::new(3800.0, 1.0),
+        effects_default: Spring::new(1600.0, 1.0),
+        effects_slow: Spring::new(800.0, 1.0),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::easing;
+
+    #[test]
+    fn easing_curves() {
+        for curve in [easing::EMPHASIZED, easing::STANDARD, easing::LINEAR] {
+            assert_eq!(curve.ease(0.0), 0.0);
+            assert_eq!(curve.ease(1.0), 1.0);
+        }
+
+        assert!((easing::LINEAR.ease(0.3) - 0.3).abs() < 1e-4);
+        assert!(easing::EMPHASIZED_DECELERATE.ease(0.5) > 0.5);
+        assert!(easing::EMPHASIZED_ACCELERATE.ease(0.5) < 0.5);
+    }
+}
@@ -0,0 +1,431 @@
+//! The destination model shared by the navigation components.
+//!
+//! A [`Destination`] is a value identifying a page of the application, along
+//! with the icon, label and optional [`Badge`] used to show it in a
+//! [`NavigationBar`](crate::navigation_bar::NavigationBar), a
+//! [`NavigationDrawer`](crate::navigation_drawer::NavigationDrawer) or a
+//! [`NavigationRail`](crate::navigation_rail::NavigationRail).
+use std::fmt;
+
+use iced_widget::core::text::{
+    self, Alignment, Fragment, IntoFragment, LineHeight, Paragraph, Shaping,
+    Wrapping,
+};
+use iced_widget::core::{
+    self, Background, Color, Element, Event, Font, Pixels, Point, Rectangle,
+    Shell, Size, alignment, border, mouse, renderer, touch,
+};
+use iced_widget::{Container, Space, Text};
+
+use crate::Theme;
+use crate::typography::{TypeStyle, Typography};
+use crate::utils::State;
+
+/// The size of a destination icon, in logical pixels.
+pub const ICON_SIZE: f32 = 24.0;
+
+/// The size of a small badge, in logical pixels.
+const DOT_SIZE: f32 = 6.0;
+
+/// The height of a large badge, in logical pixels.
+const BADGE_HEIGHT: f32 = 16.0;
+
+/// A badge shown on top of the icon of a [`Destination`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Badge {
+    /// A small dot, signaling new content.
+    Dot,
+    /// A count, like the number of unread messages.
+    Count(u32),
+}
+
+impl Badge {
+    /// The largest count shown in full. Larger counts show as `999+`.
+    pub const MAX_COUNT: u32 = 999;
+
+    /// The text of the [`Badge`], if any.
+    pub fn label(self) -> Option<String> {
+        match self {
+            Self::Dot => None,
+            Self::Count(count) if count > Self::MAX_COUNT => {
+                Some(format!("{}+", Self::MAX_COUNT))
+            }
+            Self::Count(count) => Some(count.to_string()),
+        }
+    }
+}
+
+/// When the labels of the destinations are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LabelVisibility {
+    #[default]
+    Always,
+    /// Only the label of the selected destination is shown.
+    Selected,
+    Never,
+}
+
+impl LabelVisibility {
+    /// Whether the label of a destination is shown.
+    pub fn is_visible(self, is_selected: bool) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Selected => is_selected,
+            Self::Never => false,
+        }
+    }
+}
+
+/// A destination of a navigation component.
+pub struct Destination<'a, T, Message, Renderer = iced_widget::Renderer> {
+    pub(crate) value: T,
+    pub(crate) icon: Element<'a, Message, Theme, Renderer>,
+    pub(crate) selected_icon: Option<Element<'a, Message, Theme, Renderer>>,
+    pub(crate) label: Fragment<'a>,
+    pub(crate) badge: Option<Badge>,
+}
+
+impl<'a, T, Message, Renderer> Destination<'a, T, Message, Renderer> {
+    /// Creates a [`Destination`] with the given value, icon and label.
+    pub fn new(
+        value: T,
+        icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+        label: impl IntoFragment<'a>,
+    ) -> Self {
+        Self {
+            value,
+            icon: icon.into(),
+            selected_icon: None,
+            label: label.into_fragment(),
+            badge: None,
+        }
+    }
+
+    /// Sets the icon shown while the [`Destination`] is selected, usually a
+    /// filled variant of its icon.
+    pub fn selected_icon(
+        self,
+        icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            selected_icon: Some(icon.into()),
+            ..self
+        }
+    }
+
+    /// Sets the [`Badge`] of the [`Destination`].
+    pub fn badge(self, badge: impl Into<Option<Badge>>) -> Self {
+        Self {
+            badge: badge.into(),
+            ..self
+        }
+    }
+}
+
+impl<T, Message, Renderer> fmt::Debug for Destination<'_, T, Message, Renderer>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Destination")
+            .field("value", &self.value)
+            .field("label", &self.label)
+            .field("badge", &self.badge)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The destinations of a navigation component, turned into widgets.
+pub(crate) struct Items<'a, T, Message, Renderer> {
+    pub(crate) values: Vec<T>,
+    /// The icon and label of each destination, interleaved.
+    pub(crate) children: Vec<Element<'a, Message, Theme, Renderer>>,
+    pub(crate) badges: Vec<Option<Badge>>,
+    pub(crate) selected: Option<usize>,
+}
+
+impl<'a, T, Message, Renderer> Items<'a, T, Message, Renderer>
+where
+    T: PartialEq,
+    Message: 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    /// Turns the given destinations into widgets, with labels of the given
+    /// [`TypeStyle`].
+    pub(crate) fn new(
+        destinations: impl IntoIterator<
+            Item = Destination<'a, T, Message, Renderer>,
+        >,
+        selected: Option<&T>,
+        label: TypeStyle,
+    ) -> Self {
+        let mut items = Self {
+            values: Vec::new(),
+            children: Vec::new(),
+            badges: Vec::new(),
+            selected: None,
+        };
+
+        for destination in destinations {
+            let is_selected = selected == Some(&destination.value);
+
+            if is_selected {
+                items.selected = Some(items.values.len());
+            }
+
+            let icon = if is_selected {
+                destination.selected_icon.unwrap_or(destination.icon)
+            } else {
+                destination.icon
+            };
+
+            items
+                .children
+                .push(Container::new(icon).center(ICON_SIZE).into());
+            items.children.push(
+                label
+                    .apply(Text::new(destination.label))
+                    .wrapping(Wrapping::None)
+                    .into(),
+            );
+            items.badges.push(destination.badge);
+            items.values.push(destination.value);
+        }
+
+        items
+    }
+}
+
+/// The hovered and pressed destinations of a navigation component.
+#[derive(Debug, Default)]
+pub(crate) struct Selection {
+    hovered: Option<usize>,
+    pressed: Option<usize>,
+}
+
+impl Selection {
+    /// Processes an [`Event`], given the index of the destination under the
+    /// cursor.
+    ///
+    /// Returns the index of the destination to select, if any.
+    pub(crate) fn update<Message>(
+        &mut self,
+        event: &Event,
+        position: Option<usize>,
+        shell: &mut Shell<'_, Message>,
+    ) -> Option<usize> {
+        match event {
+            Event::Mouse(
+                mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft,
+            ) => {
+                if position != self.hovered {
+                    self.hovered = position;
+                    shell.request_redraw();
+                }
+
+                None
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if position.is_some() {
+                    self.pressed = position;
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+
+                None
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(
+                touch::Event::FingerLifted { .. }
+                | touch::Event::FingerLost { .. },
+            ) => {
+                let pressed = self.pressed.take()?;
+
+                shell.capture_event();
+                shell.request_redraw();
+
+                (position == Some(pressed)).then_some(pressed)
+            }
+            _ => None,
+        }
+    }
+
+    /// The [`State`] of the destination at the given index.
+    pub(crate) fn state(&self, index: usize) -> State {
+        if self.pressed == Some(index) {
+            State::Pressed
+        } else if self.hovered == Some(index) {
+            State::Hovered
+        } else {
+            State::Enabled
+        }
+    }
+}
+
+/// The color of an active indicator in the given [`State`].
+///
+/// Unselected destinations only show the state layer.
+pub fn indicator(theme: &Theme, is_selected: bool, state: State) -> Color {
+    let colors = theme.colors();
+
+    let (container, content) = if is_selected {
+        (
+            colors.secondary.secondary_container,
+            colors.secondary.on_secondary_container,
+        )
+    } else {
+        (Color::TRANSPARENT, colors.surface.on_surface)
+    };
+
+    theme
+        .state_layer()
+        .compose(container, content, colors.surface.on_surface, state)
+        .0
+}
+
+/// The color of the icon of a destination.
+pub fn icon_color(theme: &Theme, is_selected: bool) -> Color {
+    let colors = theme.colors();
+
+    if is_selected {
+        colors.secondary.on_secondary_container
+    } else {
+        colors.surface.on_surface_variant
+    }
+}
+
+/// The color of the label of a destination.
+pub fn label_color(theme: &Theme, is_selected: bool) -> Color {
+    let colors = theme.colors();
+
+    if is_selected {
+        colors.secondary.color
+    } else {
+        colors.surface.on_surface_variant
+    }
+}
+
+/// Creates a [`Badge`] shown at the end of a destination, next to its label.
+pub(crate) fn trailing_badge<'a, Message, Renderer>(
+    typography: &Typography,
+    badge: Badge,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    match badge.label() {
+        Some(label) => typography.label_large.apply(Text::new(label)).into(),
+        None => Container::new(Space::new())
+            .width(DOT_SIZE)
+            .height(DOT_SIZE)
+            .style(dot)
+            .into(),
+    }
+}
+
+/// The style of a dot [`Badge`] shown at the end of a destination.
+fn dot(theme: &Theme) -> iced_widget::container::Style {
+    iced_widget::container::Style {
+        background: Some(Background::Color(theme.colors().error.color)),
+        border: border::rounded(DOT_SIZE / 2.0),
+        ..iced_widget::container::Style::default()
+    }
+}
+
+/// Draws the given [`Badge`] on top of the bounds of an icon.
+pub(crate) fn draw_badge<Renderer>(
+    renderer: &mut Renderer,
+    theme: &Theme,
+    badge: Badge,
+    icon: Rectangle,
+    viewport: &Rectangle,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
+    let colors = theme.colors();
+    let style = theme.typography().label_small;
+
+    let Some(label) = badge.label() else {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle::new(
+                    Point::new(icon.x + icon.width - DOT_SIZE, icon.y),
+                    Size::new(DOT_SIZE, DOT_SIZE),
+                ),
+                border: border::rounded(DOT_SIZE / 2.0),
+                snap: cfg!(feature = "crisp"),
+                ..renderer::Quad::default()
+            },
+            Background::Color(colors.error.color),
+        );
+
+        return;
+    };
+
+    let text = text::Text {
+        content: label,
+        bounds: Size::INFINITE,
+        size: Pixels(style.size),
+        line_height: LineHeight::Absolute(Pixels(BADGE_HEIGHT)),
+        font: style.font(),
+        align_x: Alignment::Center,
+        align_y: alignment::Vertical::Center,
+        shaping: Shaping::Basic,
+        wrapping: Wrapping::None,
+    };
+
+    let paragraph = Renderer::Paragraph::with_text(
+        text.with_content(text.content.as_str()),
+    );
+    let width = (paragraph.min_width() + 8.0).max(BADGE_HEIGHT);
+
+    let bounds = Rectangle::new(
+        Point::new(icon.center_x() + 4.0, icon.y - 4.0),
+        Size::new(width, BADGE_HEIGHT),
+    );
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: border::rounded(BADGE_HEIGHT / 2.0),
+            snap: cfg!(feature = "crisp"),
+            ..renderer::Quad::default()
+        },
+        Background::Color(colors.error.color),
+    );
+
+    renderer.fill_text(
+        text::Text {
+            bounds: bounds.size(),
+            ..text
+        },
+        bounds.center(),
+        colors.error.on_error,
+        *viewport,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Badge, LabelVisibility};
+
+    #[test]
+    fn badge_labels() {
+        assert_eq!(Badge::Dot.label(), None);
+        assert_eq!(Badge::Count(0).label().as_deref(), Some("0"));
+        assert_eq!(Badge::Count(999).label().as_deref(), Some("999"));
+        assert_eq!(Badge::Count(1000).label().as_deref(), Some("999+"));
+    }
+
+    #[test]
+    fn label_visibility() {
+        assert!(LabelVisibility::Always.is_visible(false));
+        assert!(LabelVisibility::Selected.is_visible(true));
+        assert!(!LabelVisibility::Selected.is_visible(false));
+        assert!(!LabelVisibility::Never.is_visible(true));
+    }
+}
@@ -0,0 +1,485 @@
+//! [Navigation bars](https://m3.material.io/components/navigation-bar/overview).
+//!
+//! A [`NavigationBar`] lets users switch between three to five
+//! [`Destination`]s on compact screens. The selected destination has a
+//! pill-shaped active indicator behind its icon, which expands from its
+//! center when the `animate` feature is enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced_material::Theme;
+//! use iced_material::navigation::{Badge, Destination};
+//! use iced_material::navigation_bar::NavigationBar;
+//! use iced_widget::text;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum Page {
+//!     Home,
+//!     Inbox,
+//!     Settings,
+//! }
+//!
+//! # fn view<'a>(theme: &Theme, page: Page) -> iced_widget::core::Element<'a, Page, Theme, iced_widget::Renderer> {
+//! NavigationBar::new(
+//!     theme,
+//!     [
+//!         Destination::new(Page::Home, text("H"), "Home"),
+//!         Destination::new(Page::Inbox, text("I"), "Inbox")
+//!             .badge(Badge::Count(3)),
+//!         Destination::new(Page::Settings, text("S"), "Settings"),
+//!     ],
+//!     Some(page),
+//!     |page| page,
+//! )
+//! .into()
+//! # }
+//! ```
+use std::fmt;
+
+use iced_widget::core::layout::{self, Layout, Limits};
+use iced_widget::core::time::Instant;
+use iced_widget::core::widget::{Operation, Tree, tree};
+use iced_widget::core::{
+    self, Background, Clipboard, Element, Event, Font, Length, Point,
+    Rectangle, Shell, Size, Widget, border, mouse, renderer, window,
+};
+
+use crate::Theme;
+use crate::motion::{duration, easing};
+use crate::navigation::{
+    self, Badge, Destination, Items, LabelVisibility, Selection,
+};
+use crate::typography::Typography;
+
+/// The height of a navigation bar, in logical pixels.
+pub const HEIGHT: f32 = 80.0;
+
+/// The width of the active indicator, in logical pixels.
+const INDICATOR_WIDTH: f32 = 64.0;
+
+/// The height of the active indicator, in logical pixels.
+const INDICATOR_HEIGHT: f32 = 32.0;
+
+/// The space above the active indicator when the label is visible.
+const PADDING_TOP: f32 = 12.0;
+
+/// The space between the active indicator and the label.
+const LABEL_SPACING: f32 = 4.0;
+
+/// A bar of destinations for compact screens.
+pub struct NavigationBar<'a, T, Message, Renderer = iced_widget::Renderer> {
+    typography: Typography,
+    destinations: Vec<Destination<'a, T, Message, Renderer>>,
+    selected: Option<T>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    label_visibility: LabelVisibility,
+}
+
+impl<'a, T, Message, Renderer> NavigationBar<'a, T, Message, Renderer>
+where
+    T: Clone + PartialEq + 'a,
+    Message: 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    /// Creates a [`NavigationBar`] with the given destinations, producing
+    /// `on_select` when one of them is pressed.
+    ///
+    /// The labels use the typography of the [`Theme`].
+    pub fn new(
+        theme: &Theme,
+        destinations: impl IntoIterator<
+            Item = Destination<'a, T, Message, Renderer>,
+        >,
+        selected: Option<T>,
+        on_select: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        Self {
+            typography: theme.typography(),
+            destinations: destinations.into_iter().collect(),
+            selected,
+            on_select: Box::new(on_select),
+            label_visibility: LabelVisibility::default(),
+        }
+    }
+
+    /// Sets when the labels of the destinations are shown.
+    pub fn label_visibility(self, label_visibility: LabelVisibility) -> Self {
+        Self {
+            label_visibility,
+            ..self
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> From<NavigationBar<'a, T, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + PartialEq + 'a,
+    Message: 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    fn from(bar: NavigationBar<'a, T, Message, Renderer>) -> Self {
+        let items = Items::new(
+            bar.destinations,
+            bar.selected.as_ref(),
+            bar.typography.label_medium,
+        );
+
+        let widget = Bar {
+            values: items.values,
+            children: items.children,
+            badges: items.badges,
+            selected: items.selected,
+            on_select: bar.on_select,
+            label_visibility: bar.label_visibility,
+        };
+
+        Element::new(widget)
+    }
+}
+
+impl<T, Message, Renderer> fmt::Debug
+    for NavigationBar<'_, T, Message, Renderer>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NavigationBar")
+            .field("destinations", &self.destinations)
+            .field("selected", &self.selected)
+            .field("label_visibility", &self.label_visibility)
+            .finish_non_exhaustive()
+    }
+}
+
+struct Bar<'a, T, Message, Renderer> {
+    values: Vec<T>,
+    /// The icon and label of each destination, interleaved.
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    badges: Vec<Option<Badge>>,
+    selected: Option<usize>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    label_visibility: LabelVisibility,
+}
+
+#[derive(Debug)]
+struct State {
+    selected: Option<usize>,
+    progress: f32,
+    last_tick: Option<Instant>,
+    selection: Selection,
+}
+
+impl State {
+    fn select(&mut self, selected: Option<usize>) {
+        self.selected = selected;
+        self.progress = if cfg!(feature = "animate") { 0.0 } else { 1.0 };
+        self.last_tick = None;
+    }
+
+    fn tick(&mut self, now: Instant) {
+        let elapsed = self
+            .last_tick
+            .map_or(0.0, |last| (now - last).as_secs_f32());
+
+        self.progress = (self.progress
+            + elapsed / duration::MEDIUM2.as_secs_f32())
+        .min(1.0);
+        self.last_tick = (self.progress < 1.0).then_some(now);
+    }
+}
+
+impl<T, Message, Renderer> Bar<'_, T, Message, Renderer> {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// The bounds of the destination at the given index.
+    fn cell(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        let width = bounds.width / self.len().max(1) as f32;
+
+        Rectangle {
+            x: bounds.x + width * index as f32,
+            width,
+            ..bounds
+        }
+    }
+
+    fn position(
+        &self,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        (0..self.len()).find(|index| cursor.is_over(self.cell(bounds, *index)))
+    }
+
+    /// The vertical offset of the active indicator of a destination.
+    fn indicator_y(&self, index: usize) -> f32 {
+        if self
+            .label_visibility
+            .is_visible(self.selected == Some(index))
+        {
+            PADDING_TOP
+        } else {
+            (HEIGHT - INDICATOR_HEIGHT) / 2.0
+        }
+    }
+}
+
+impl<T, Message, Renderer> Widget<Message, Theme, Renderer>
+    for Bar<'_, T, Message, Renderer>
+where
+    T: Clone,
+    Renderer: core::text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            selected: self.selected,
+            progress: 1.0,
+            last_tick: None,
+            selection: Selection::default(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fixed(HEIGHT))
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &Limits,
+    ) -> layout::Node {
+        let size = limits.resolve(Length::Fill, HEIGHT, Size::ZERO);
+        let bounds = Rectangle::with_size(size);
+        let loose = limits.loose();
+
+        let nodes = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(child, tree)| {
+                child.as_widget_mut().layout(tree, renderer, &loose)
+            })
+            .collect::<Vec<_>>()
+            .chunks(2)
+            .enumerate()
+            .flat_map(|(index, nodes)| {
+                let cell = self.cell(bounds, index);
+                let indicator_y = self.indicator_y(index);
+
+                let icon = nodes[0].size();
+                let label = nodes[1].size();
+
+                [
+                    nodes[0].clone().move_to(Point::new(
+                        cell.center_x() - icon.width / 2.0,
+                        indicator_y + (INDICATOR_HEIGHT - icon.height) / 2.0,
+                    )),
+                    nodes[1].clone().move_to(Point::new(
+                        cell.center_x() - label.width / 2.0,
+                        indicator_y + INDICATOR_HEIGHT + LABEL_SPACING,
+                    )),
+                ]
+            })
+            .collect();
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if state.selected != self.selected {
+                state.select(self.selected);
+            }
+
+            if state.progress < 1.0 {
+                state.tick(*now);
+                shell.request_redraw();
+            }
+        }
+
+        let position = self.position(bounds, cursor);
+
+        if let Some(index) = state.selection.update(event, position, shell) {
+            shell.publish((self.on_select)(self.values[index].clone()));
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.position(layout.bounds(), cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                snap: cfg!(feature = "crisp"),
+                ..renderer::Quad::default()
+            },
+            Background::Color(theme.colors().surface.surface_container.base),
+        );
+
+        let progress = if state.selected == self.selected {
+            easing::EMPHASIZED.ease(state.progress)
+        } else if cfg!(feature = "animate") {
+            0.0
+        } else {
+            1.0
+        };
+
+        let mut children = self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children());
+
+        for index in 0..self.len() {
+            let (Some(icon), Some(label)) = (children.next(), children.next())
+            else {
+                break;
+            };
+
+            let is_selected = self.selected == Some(index);
+            let cell = self.cell(bounds, index);
+
+            let interaction = state.selection.state(index);
+
+            let width = if is_selected {
+                INDICATOR_WIDTH * progress
+            } else {
+                INDICATOR_WIDTH
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: cell.center_x() - width / 2.0,
+                        y: bounds.y + self.indicator_y(index),
+                        width,
+                        height: INDICATOR_HEIGHT,
+                    },
+                    border: border::rounded(theme.shapes().full),
+                    snap: cfg!(feature = "crisp"),
+                    ..renderer::Quad::default()
+                },
+                Background::Color(navigation::indicator(
+                    theme,
+                    is_selected,
+                    interaction,
+                )),
+            );
+
+            let ((icon, icon_tree), icon_layout) = icon;
+
+            icon.as_widget().draw(
+                icon_tree,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: navigation::icon_color(theme, is_selected),
+                },
+                icon_layout,
+                cursor,
+                viewport,
+            );
+
+            if let Some(badge) = self.badges[index] {
+                navigation::draw_badge(
+                    renderer,
+                    theme,
+                    badge,
+                    icon_layout.bounds(),
+                    viewport,
+                );
+            }
+
+            if self.label_visibility.is_visible(is_selected) {
+                let ((label, label_tree), label_layout) = label;
+
+                label.as_widget().draw(
+                    label_tree,
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        text_color: navigation::label_color(theme, is_selected),
+                    },
+                    label_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,682 @@
+//! [Navigation drawers](https://m3.material.io/components/navigation-drawer/overview).
+//!
+//! A [`NavigationDrawer`] lists the [`Destination`]s of an application,
+//! optionally grouped under section headers. The selected destination is
+//! highlighted with a full-width active indicator, and badges show up as
+//! trailing counters.
+//!
+//! A standard drawer is placed next to the content, while a [`Modal`] one
+//! slides in over a scrim and closes when clicking outside of it or pressing
+//! <kbd>Escape</kbd>.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced_material::Theme;
+//! use iced_material::navigation::{Badge, Destination};
+//! use iced_material::navigation_drawer::NavigationDrawer;
+//! use iced_widget::text;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum Message {
+//!     Open(Page),
+//!     CloseDrawer,
+//! }
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum Page {
+//!     Inbox,
+//!     Sent,
+//!     Family,
+//! }
+//!
+//! # fn view<'a>(theme: &Theme, page: Page, is_open: bool) -> iced_widget::core::Element<'a, Message, Theme, iced_widget::Renderer> {
+//! NavigationDrawer::new(theme, Some(page), Message::Open)
+//!     .section("Mail")
+//!     .push(
+//!         Destination::new(Page::Inbox, text("I"), "Inbox")
+//!             .badge(Badge::Count(24)),
+//!     )
+//!     .push(Destination::new(Page::Sent, text("S"), "Sent"))
+//!     .divider()
+//!     .section("Labels")
+//!     .push(Destination::new(Page::Family, text("F"), "Family"))
+//!     .modal(text("Content"), is_open, Message::CloseDrawer)
+//!     .into()
+//! # }
+//! ```
+use std::fmt;
+
+use iced_widget::button::{Status, Style};
+use iced_widget::core::keyboard::{self, key};
+use iced_widget::core::layout::{self, Layout, Limits};
+use iced_widget::core::text::{Fragment, IntoFragment};
+use iced_widget::core::time::Instant;
+use iced_widget::core::widget::{Operation, Tree, tree};
+use iced_widget::core::{
+    self, Alignment, Background, Clipboard, Color, Element, Event, Font,
+    Length, Padding, Rectangle, Shell, Size, Vector, Widget, border, mouse,
+    overlay, renderer, touch, window,
+};
+use iced_widget::{Button, Column, Container, Row, Scrollable, Text};
+
+use crate::motion::{duration, easing};
+use crate::navigation::{self, Destination, ICON_SIZE};
+use crate::typography::Typography;
+use crate::{Theme, container, rule};
+
+/// The width of a navigation drawer, in logical pixels.
+pub const WIDTH: f32 = 360.0;
+
+/// The height of a destination or section header, in logical pixels.
+pub const ITEM_HEIGHT: f32 = 56.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Standard,
+    Modal,
+}
+
+enum Entry<'a, T, Message, Renderer> {
+    Section(Fragment<'a>),
+    Destination(Destination<'a, T, Message, Renderer>),
+    Divider,
+}
+
+/// A list of destinations for large screens or hamburger menus.
+pub struct NavigationDrawer<'a, T, Message, Renderer = iced_widget::Renderer> {
+    typography: Typography,
+    entries: Vec<Entry<'a, T, Message, Renderer>>,
+    selected: Option<T>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    kind: Kind,
+}
+
+impl<'a, T, Message, Renderer> NavigationDrawer<'a, T, Message, Renderer>
+where
+    T: PartialEq,
+    Message: Clone + 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    /// Creates an empty [`NavigationDrawer`], producing `on_select` when one
+    /// of its destinations is pressed.
+    ///
+    /// The labels use the typography of the [`Theme`].
+    pub fn new(
+        theme: &Theme,
+        selected: Option<T>,
+        on_select: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        Self {
+            typography: theme.typography(),
+            entries: Vec::new(),
+            selected,
+            on_select: Box::new(on_select),
+            kind: Kind::Standard,
+        }
+    }
+
+    /// Adds a [`Destination`] to the [`NavigationDrawer`].
+    pub fn push(
+        mut self,
+        destination: Destination<'a, T, Message, Renderer>,
+    ) -> Self {
+        self.entries.push(Entry::Destination(destination));
+        self
+    }
+
+    /// Adds a section header to the [`NavigationDrawer`].
+    pub fn section(mut self, label: impl IntoFragment<'a>) -> Self {
+        self.entries.push(Entry::Section(label.into_fragment()));
+        self
+    }
+
+    /// Adds a divider between two sections of the [`NavigationDrawer`].
+    pub fn divider(mut self) -> Self {
+        self.entries.push(Entry::Divider);
+        self
+    }
+
+    /// Turns the [`NavigationDrawer`] into a [`Modal`] shown on top of the
+    /// given content.
+    pub fn modal(
+        self,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        is_open: bool,
+        on_close: Message,
+    ) -> Modal<'a, Message, Renderer> {
+        Modal::new(
+            content,
+            Self {
+                kind: Kind::Modal,
+                ..self
+            },
+            is_open,
+            on_close,
+        )
+    }
+}
+
+impl<T, Message, Renderer> fmt::Debug
+    for NavigationDrawer<'_, T, Message, Renderer>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NavigationDrawer")
+            .field("entries", &self.entries.len())
+            .field("selected", &self.selected)
+            .field("kind", &self.kind)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T, Message, Renderer> From<NavigationDrawer<'a, T, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: PartialEq,
+    Message: Clone + 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    fn from(drawer: NavigationDrawer<'a, T, Message, Renderer>) -> Self {
+        let typography = drawer.typography;
+        let mut column = Column::new().padding(12);
+
+        for entry in drawer.entries {
+            column = match entry {
+                Entry::Section(label) => column.push(
+                    Container::new(
+                        typography.title_small.apply(Text::new(label)),
+                    )
+                    .height(ITEM_HEIGHT)
+                    .padding(Padding::from([0, 16]))
+                    .align_y(Alignment::Center),
+                ),
+                Entry::Destination(destination) => {
+                    let is_selected =
+                        drawer.selected.as_ref() == Some(&destination.value);
+                    let on_press = (drawer.on_select)(destination.value);
+
+                    let icon = if is_selected {
+                        destination.selected_icon.unwrap_or(destination.icon)
+                    } else {
+                        destination.icon
+                    };
+
+                    let badge = destination.badge.map(|badge| {
+                        navigation::trailing_badge(&typography, badge)
+                    });
+
+                    let content = Row::new()
+                        .push(Container::new(icon).center(ICON_SIZE))
+                        .push(
+                            typography
+                                .label_large
+                                .apply(Text::new(destination.label))
+                                .width(Length::Fill),
+                        )
+                        .push(badge)
+                        .spacing(12)
+                        .align_y(Alignment::Center);
+
+                    column.push(
+                        Button::new(content)
+                            .width(Length::Fill)
+                            .height(ITEM_HEIGHT)
+                            .padding(Padding {
+                                left: 16.0,
+                                right: 24.0,
+                                ..Padding::ZERO
+                            })
+                            .on_press(on_press)
+                            .style(item_with(is_selected)),
+                    )
+                }
+                Entry::Divider => column.push(
+                    Container::new(
+                        iced_widget::rule::horizontal(1)
+                            .style(rule::full_width),
+                    )
+                    .padding(Padding::from([0, 16])),
+                ),
+            };
+        }
+
+        let style = match drawer.kind {
+            Kind::Standard => container::navigation_drawer,
+            Kind::Modal => container::modal_navigation_drawer,
+        };
+
+        Container::new(Scrollable::new(column))
+            .width(WIDTH)
+            .height(Length::Fill)
+            .style(style)
+            .into()
+    }
+}
+
+/// A destination of a navigation drawer, using the full-width active
+/// indicator shape while selected.
+pub fn item_with(is_selected: bool) -> impl Fn(&Theme, Status) -> Style + Copy {
+    move |theme, status| {
+        let colors = theme.colors();
+        let surface = colors.surface;
+
+        let (container, content) = if is_selected {
+            (
+                colors.secondary.secondary_container,
+                colors.secondary.on_secondary_container,
+            )
+        } else {
+            (Color::TRANSPARENT, surface.on_surface_variant)
+        };
+
+        let (background, text_color) = theme.state_layer().compose(
+            container,
+            content,
+            surface.on_surface,
+            status.into(),
+        );
+
+        Style {
+            background: Some(Background::Color(background)),
+            text_color,
+            border: border::rounded(theme.shapes().full),
+            snap: cfg!(feature = "crisp"),
+            ..Style::default()
+        }
+    }
+}
+
+/// A drawer that slides in over some content, dimming it with a scrim.
+///
+/// Clicking outside of the drawer or pressing <kbd>Escape</kbd> produces the
+/// `on_close` message.
+pub struct Modal<'a, Message, Renderer = iced_widget::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    drawer: Element<'a, Message, Theme, Renderer>,
+    is_open: bool,
+    on_close: Message,
+}
+
+impl<'a, Message, Renderer> Modal<'a, Message, Renderer> {
+    /// Creates a [`Modal`] showing the given drawer on top of the content
+    /// while open.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        drawer: impl Into<Element<'a, Message, Theme, Renderer>>,
+        is_open: bool,
+        on_close: Message,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            drawer: drawer.into(),
+            is_open,
+            on_close,
+        }
+    }
+}
+
+impl<Message, Renderer> fmt::Debug for Modal<'_, Message, Renderer>
+where
+    Message: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Modal")
+            .field("is_open", &self.is_open)
+            .field("on_close", &self.on_close)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    progress: f32,
+    last_tick: Option<Instant>,
+}
+
+impl State {
+    fn tick(&mut self, now: Instant, is_open: bool) {
+        let elapsed = self
+            .last_tick
+            .map_or(0.0, |last| (now - last).as_secs_f32());
+
+        self.progress = if is_open {
+            (self.progress + elapsed / duration::MEDIUM4.as_secs_f32()).min(1.0)
+        } else {
+            (self.progress - elapsed / duration::SHORT4.as_secs_f32()).max(0.0)
+        };
+
+        self.last_tick = self.is_animating(is_open).then_some(now);
+    }
+
+    fn is_animating(&self, is_open: bool) -> bool {
+        self.progress != if is_open { 1.0 } else { 0.0 }
+    }
+}
+
+impl<Message, Renderer> Modal<'_, Message, Renderer> {
+    /// Whether the drawer is shown, either open or animating.
+    fn is_visible(&self, state: &State) -> bool {
+        self.is_open || state.progress > 0.0
+    }
+
+    /// The eased progress of the animation.
+    fn progress(&self, state: &State) -> f32 {
+        if self.is_open {
+            easing::EMPHASIZED_DECELERATE.ease(state.progress)
+        } else {
+            easing::EMPHASIZED_ACCELERATE.ease(state.progress)
+        }
+    }
+
+    /// The translation of the drawer while it slides in or out.
+    fn offset(&self, state: &State, drawer: Layout<'_>) -> Vector {
+        Vector::new(-drawer.bounds().width * (1.0 - self.progress(state)), 0.0)
+    }
+
+    /// The cursor as seen by the drawer, which ignores it while closing.
+    fn drawer_cursor(
+        &self,
+        state: &State,
+        drawer: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> mouse::Cursor {
+        if self.is_open {
+            cursor - self.offset(state, drawer)
+        } else {
+            mouse::Cursor::Unavailable
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer>
+    for Modal<'_, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            progress: if self.is_open { 1.0 } else { 0.0 },
+            last_tick: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content), Tree::new(&self.drawer)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[
+            self.content.as_widget(),
+            self.drawer.as_widget(),
+        ]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &Limits,
+    ) -> layout::Node {
+        let content = self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        );
+        let size = content.size();
+
+        let drawer = self.drawer.as_widget_mut().layout(
+            &mut tree.children[1],
+            renderer,
+            &Limits::new(Size::ZERO, size),
+        );
+
+        layout::Node::with_children(size, vec![content, drawer])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let (child, tree, layout) = if self.is_open {
+            (&mut self.drawer, &mut tree.children[1], layout.child(1))
+        } else {
+            (&mut self.content, &mut tree.children[0], layout.child(0))
+        };
+
+        child
+            .as_widget_mut()
+            .operate(tree, layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event
+            && state.is_animating(self.is_open)
+        {
+            state.tick(*now, self.is_open);
+            shell.request_redraw();
+        }
+
+        let drawer = layout.child(1);
+        let offset = self.offset(state, drawer);
+        let drawer_cursor = self.drawer_cursor(state, drawer, cursor);
+
+        if !self.is_open {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                layout.child(0),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            return;
+        }
+
+        // The content only receives window events while the drawer is open.
+        if let Event::Window(_) = event {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                layout.child(0),
+                mouse::Cursor::Unavailable,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        self.drawer.as_widget_mut().update(
+            &mut tree.children[1],
+            event,
+            drawer,
+            drawer_cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !cursor.is_over(drawer.bounds() + offset) =>
+            {
+                shell.publish(self.on_close.clone());
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) => {
+                shell.publish(self.on_close.clone());
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.is_open {
+            let state = tree.state.downcast_ref::<State>();
+            let drawer = layout.child(1);
+
+            self.drawer.as_widget().mouse_interaction(
+                &tree.children[1],
+                drawer,
+                self.drawer_cursor(state, drawer, cursor),
+                viewport,
+                renderer,
+            )
+        } else {
+            self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                layout.child(0),
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.child(0),
+            if self.is_open {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
+            viewport,
+        );
+
+        if !self.is_visible(state) {
+            return;
+        }
+
+        let progress = self.progress(state);
+        let bounds = layout.bounds();
+        let drawer = layout.child(1);
+
+        renderer.with_layer(bounds, |renderer| {
+            if let Some(background) = container::scrim(theme).background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    background.scale_alpha(progress),
+                );
+            }
+
+            renderer.with_translation(self.offset(state, drawer), |renderer| {
+                self.drawer.as_widget().draw(
+                    &tree.children[1],
+                    renderer,
+                    theme,
+                    style,
+                    drawer,
+                    self.drawer_cursor(state, drawer, cursor),
+                    viewport,
+                );
+            });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        if self.is_open {
+            let state = tree.state.downcast_ref::<State>();
+            let drawer = layout.child(1);
+            let offset = self.offset(state, drawer);
+
+            self.drawer.as_widget_mut().overlay(
+                &mut tree.children[1],
+                drawer,
+                renderer,
+                viewport,
+                translation + offset,
+            )
+        } else {
+            self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout.child(0),
+                renderer,
+                viewport,
+                translation,
+            )
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Modal<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(modal: Modal<'a, Message, Renderer>) -> Self {
+        Self::new(modal)
+    }
+}
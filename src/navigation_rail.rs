@@ -0,0 +1,685 @@
+//! [Navigation rails](https://m3.material.io/components/navigation-rail/overview).
+//!
+//! A [`NavigationRail`] shows the [`Destination`]s of an application in a
+//! vertical column on medium-sized screens, with an optional menu button and
+//! FAB at the top.
+//!
+//! A collapsed rail stacks the label of each destination below its icon,
+//! while an expanded one shows it next to the icon inside a full-width active
+//! indicator, with its badge at the end like in a navigation drawer.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced_material::navigation::Destination;
+//! use iced_material::navigation_rail::{Mode, NavigationRail};
+//! use iced_material::{Theme, fab};
+//! use iced_widget::text;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum Message {
+//!     Compose,
+//!     Open(Page),
+//! }
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum Page {
+//!     Inbox,
+//!     Sent,
+//! }
+//!
+//! # fn view<'a>(theme: &Theme, page: Page) -> iced_widget::core::Element<'a, Message, Theme, iced_widget::Renderer> {
+//! NavigationRail::new(
+//!     theme,
+//!     [
+//!         Destination::new(Page::Inbox, text("I"), "Inbox"),
+//!         Destination::new(Page::Sent, text("S"), "Sent"),
+//!     ],
+//!     Some(page),
+//!     Message::Open,
+//! )
+//! .fab(fab::fab(text("+")).on_press(Message::Compose))
+//! .mode(Mode::Expanded)
+//! .into()
+//! # }
+//! ```
+use std::fmt;
+
+use iced_widget::Space;
+use iced_widget::core::alignment;
+use iced_widget::core::layout::{self, Layout, Limits};
+use iced_widget::core::widget::{Operation, Tree, tree};
+use iced_widget::core::{
+    self, Background, Clipboard, Element, Event, Font, Length, Point,
+    Rectangle, Shell, Size, Vector, Widget, border, mouse, overlay, renderer,
+};
+
+use crate::Theme;
+use crate::navigation::{self, Badge, Destination, Items, Selection};
+use crate::typography::Typography;
+
+/// The width of a collapsed navigation rail, in logical pixels.
+pub const COLLAPSED_WIDTH: f32 = 96.0;
+
+/// The width of an expanded navigation rail, in logical pixels.
+pub const EXPANDED_WIDTH: f32 = 220.0;
+
+/// The vertical space around the contents of the rail.
+const PADDING_Y: f32 = 16.0;
+
+/// The horizontal space around the header and the indicators of an expanded
+/// rail.
+const PADDING_X: f32 = 20.0;
+
+/// The space between the header and the destinations.
+const HEADER_SPACING: f32 = 40.0;
+
+/// The height of a destination of a collapsed rail.
+const COLLAPSED_ITEM_HEIGHT: f32 = 64.0;
+
+/// The height of a destination of an expanded rail.
+const EXPANDED_ITEM_HEIGHT: f32 = 56.0;
+
+/// The size of the active indicator of a collapsed rail.
+const INDICATOR_SIZE: Size = Size::new(56.0, 32.0);
+
+/// The space above the active indicator of a collapsed rail.
+const INDICATOR_TOP: f32 = 4.0;
+
+/// The space between the trailing badge and the end of the active indicator
+/// of an expanded rail.
+const BADGE_PADDING: f32 = 24.0;
+
+/// The space between the icon and the label of a destination.
+const LABEL_SPACING: f32 = 4.0;
+
+/// The mode of a navigation rail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mode {
+    #[default]
+    Collapsed,
+    Expanded,
+}
+
+impl Mode {
+    /// The width of a navigation rail in this [`Mode`].
+    pub fn width(self) -> f32 {
+        match self {
+            Self::Collapsed => COLLAPSED_WIDTH,
+            Self::Expanded => EXPANDED_WIDTH,
+        }
+    }
+
+    fn item_height(self) -> f32 {
+        match self {
+            Self::Collapsed => COLLAPSED_ITEM_HEIGHT,
+            Self::Expanded => EXPANDED_ITEM_HEIGHT,
+        }
+    }
+}
+
+/// A vertical column of destinations for medium-sized screens.
+pub struct NavigationRail<'a, T, Message, Renderer = iced_widget::Renderer> {
+    typography: Typography,
+    destinations: Vec<Destination<'a, T, Message, Renderer>>,
+    selected: Option<T>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    menu: Option<Element<'a, Message, Theme, Renderer>>,
+    fab: Option<Element<'a, Message, Theme, Renderer>>,
+    mode: Mode,
+    align_y: alignment::Vertical,
+}
+
+impl<'a, T, Message, Renderer> NavigationRail<'a, T, Message, Renderer>
+where
+    T: Clone + PartialEq + 'a,
+    Message: 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    /// Creates a [`NavigationRail`] with the given destinations, producing
+    /// `on_select` when one of them is pressed.
+    ///
+    /// The labels use the typography of the [`Theme`].
+    pub fn new(
+        theme: &Theme,
+        destinations: impl IntoIterator<
+            Item = Destination<'a, T, Message, Renderer>,
+        >,
+        selected: Option<T>,
+        on_select: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        Self {
+            typography: theme.typography(),
+            destinations: destinations.into_iter().collect(),
+            selected,
+            on_select: Box::new(on_select),
+            menu: None,
+            fab: None,
+            mode: Mode::default(),
+            align_y: alignment::Vertical::Top,
+        }
+    }
+
+    /// Sets the menu button shown at the top of the [`NavigationRail`],
+    /// usually toggling its [`Mode`].
+    pub fn menu(
+        self,
+        menu: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            menu: Some(menu.into()),
+            ..self
+        }
+    }
+
+    /// Sets the FAB shown at the top of the [`NavigationRail`], below the
+    /// menu button.
+    pub fn fab(
+        self,
+        fab: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            fab: Some(fab.into()),
+            ..self
+        }
+    }
+
+    /// Sets the [`Mode`] of the [`NavigationRail`].
+    pub fn mode(self, mode: Mode) -> Self {
+        Self { mode, ..self }
+    }
+
+    /// Sets the vertical alignment of the destinations.
+    pub fn align_y(self, align_y: impl Into<alignment::Vertical>) -> Self {
+        Self {
+            align_y: align_y.into(),
+            ..self
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> From<NavigationRail<'a, T, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + PartialEq + 'a,
+    Message: 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    fn from(rail: NavigationRail<'a, T, Message, Renderer>) -> Self {
+        let label = match rail.mode {
+            Mode::Collapsed => rail.typography.label_medium,
+            Mode::Expanded => rail.typography.label_large,
+        };
+
+        let header =
+            usize::from(rail.menu.is_some()) + usize::from(rail.fab.is_some());
+
+        let items =
+            Items::new(rail.destinations, rail.selected.as_ref(), label);
+
+        let mut children: Vec<_> =
+            rail.menu.into_iter().chain(rail.fab).collect();
+        let mut destinations = items.children.into_iter();
+
+        for badge in &items.badges {
+            children.extend(destinations.by_ref().take(2));
+
+            if rail.mode == Mode::Expanded {
+                children.push(badge.map_or_else(
+                    || Space::new().into(),
+                    |badge| navigation::trailing_badge(&rail.typography, badge),
+                ));
+            }
+        }
+
+        let widget = Rail {
+            values: items.values,
+            children,
+            header,
+            badges: items.badges,
+            selected: items.selected,
+            on_select: rail.on_select,
+            mode: rail.mode,
+            align_y: rail.align_y,
+        };
+
+        Element::new(widget)
+    }
+}
+
+impl<T, Message, Renderer> fmt::Debug
+    for NavigationRail<'_, T, Message, Renderer>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NavigationRail")
+            .field("destinations", &self.destinations)
+            .field("selected", &self.selected)
+            .field("mode", &self.mode)
+            .field("align_y", &self.align_y)
+            .finish_non_exhaustive()
+    }
+}
+
+struct Rail<'a, T, Message, Renderer> {
+    values: Vec<T>,
+    /// The header, followed by the icon and label of each destination and,
+    /// in an expanded rail, its trailing badge.
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    header: usize,
+    badges: Vec<Option<Badge>>,
+    selected: Option<usize>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    mode: Mode,
+    align_y: alignment::Vertical,
+}
+
+impl<T, Message, Renderer> Rail<'_, T, Message, Renderer> {
+    /// The number of children of each destination.
+    fn stride(&self) -> usize {
+        match self.mode {
+            Mode::Collapsed => 2,
+            Mode::Expanded => 3,
+        }
+    }
+
+    /// The layouts of the destinations, each holding an icon, a label and,
+    /// in an expanded rail, a trailing badge.
+    fn destinations<'b>(
+        &self,
+        layout: Layout<'b>,
+    ) -> impl Iterator<Item = Layout<'b>> {
+        layout.children().skip(self.header)
+    }
+
+    fn position(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        self.destinations(layout)
+            .position(|destination| cursor.is_over(destination.bounds()))
+    }
+
+    /// The bounds of the active indicator of a destination.
+    fn indicator(&self, cell: Rectangle) -> Rectangle {
+        match self.mode {
+            Mode::Collapsed => Rectangle::new(
+                Point::new(
+                    cell.center_x() - INDICATOR_SIZE.width / 2.0,
+                    cell.y + INDICATOR_TOP,
+                ),
+                INDICATOR_SIZE,
+            ),
+            Mode::Expanded => Rectangle {
+                x: cell.x + PADDING_X,
+                width: cell.width - PADDING_X * 2.0,
+                ..cell
+            },
+        }
+    }
+}
+
+impl<T, Message, Renderer> Widget<Message, Theme, Renderer>
+    for Rail<'_, T, Message, Renderer>
+where
+    T: Clone,
+    Renderer: core::text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Selection>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Selection::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.mode.width()), Length::Fill)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &Limits,
+    ) -> layout::Node {
+        let width = self.mode.width();
+        let item_height = self.mode.item_height();
+        let loose = limits.loose();
+
+        let mut nodes: Vec<_> = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(child, tree)| {
+                child.as_widget_mut().layout(tree, renderer, &loose)
+            })
+            .collect();
+
+        let destinations = nodes.split_off(self.header);
+
+        let mut y = PADDING_Y;
+
+        for node in &mut nodes {
+            let size = node.size();
+            let x = match self.mode {
+                Mode::Collapsed => (width - size.width) / 2.0,
+                Mode::Expanded => PADDING_X,
+            };
+
+            node.move_to_mut(Point::new(x, y));
+            y += size.height + 8.0;
+        }
+
+        let header = if nodes.is_empty() {
+            PADDING_Y
+        } else {
+            y - 8.0 + HEADER_SPACING
+        };
+
+        let content = (destinations.len() / self.stride()) as f32 * item_height;
+        let size = limits.resolve(
+            width,
+            Length::Fill,
+            Size::new(width, header + content + PADDING_Y),
+        );
+
+        let top = match self.align_y {
+            alignment::Vertical::Top => header,
+            alignment::Vertical::Center => (size.height - content) / 2.0,
+            alignment::Vertical::Bottom => size.height - PADDING_Y - content,
+        }
+        .max(header);
+
+        nodes.extend(destinations.chunks(self.stride()).enumerate().map(
+            |(index, nodes)| {
+                let icon = nodes[0].size();
+                let label = nodes[1].size();
+
+                let (icon, label) = match self.mode {
+                    Mode::Collapsed => (
+                        Point::new(
+                            (width - icon.width) / 2.0,
+                            INDICATOR_TOP
+                                + (INDICATOR_SIZE.height - icon.height) / 2.0,
+                        ),
+                        Point::new(
+                            (width - label.width) / 2.0,
+                            INDICATOR_TOP
+                                + INDICATOR_SIZE.height
+                                + LABEL_SPACING,
+                        ),
+                    ),
+                    Mode::Expanded => (
+                        Point::new(
+                            PADDING_X + 16.0,
+                            (item_height - icon.height) / 2.0,
+                        ),
+                        Point::new(
+                            PADDING_X + 16.0 + icon.width + 8.0,
+                            (item_height - label.height) / 2.0,
+                        ),
+                    ),
+                };
+
+                let mut children = vec![
+                    nodes[0].clone().move_to(icon),
+                    nodes[1].clone().move_to(label),
+                ];
+
+                if let Some(badge) = nodes.get(2) {
+                    let size = badge.size();
+
+                    children.push(badge.clone().move_to(Point::new(
+                        width - PADDING_X - BADGE_PADDING - size.width,
+                        (item_height - size.height) / 2.0,
+                    )));
+                }
+
+                layout::Node::with_children(
+                    Size::new(width, item_height),
+                    children,
+                )
+                .move_to(Point::new(0.0, top + index as f32 * item_height))
+            },
+        ));
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .take(self.header)
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((child, tree), layout) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .take(self.header)
+        {
+            child.as_widget_mut().update(
+                tree, event, layout, cursor, renderer, clipboard, shell,
+                viewport,
+            );
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let selection = tree.state.downcast_mut::<Selection>();
+        let position = self.position(layout, cursor);
+
+        if let Some(index) = selection.update(event, position, shell) {
+            shell.publish((self.on_select)(self.values[index].clone()));
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.position(layout, cursor).is_some() {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .take(self.header)
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let selection = tree.state.downcast_ref::<Selection>();
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                snap: cfg!(feature = "crisp"),
+                ..renderer::Quad::default()
+            },
+            Background::Color(theme.colors().surface.color),
+        );
+
+        for ((child, tree), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .take(self.header)
+        {
+            child
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+
+        let destinations = self.children[self.header..]
+            .chunks(self.stride())
+            .zip(tree.children[self.header..].chunks(self.stride()))
+            .zip(self.destinations(layout))
+            .enumerate();
+
+        for (index, ((children, trees), cell)) in destinations {
+            let is_selected = self.selected == Some(index);
+
+            let interaction = selection.state(index);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: self.indicator(cell.bounds()),
+                    border: border::rounded(theme.shapes().full),
+                    snap: cfg!(feature = "crisp"),
+                    ..renderer::Quad::default()
+                },
+                Background::Color(navigation::indicator(
+                    theme,
+                    is_selected,
+                    interaction,
+                )),
+            );
+
+            let mut layouts = cell.children();
+
+            let (Some(icon), Some(label)) = (layouts.next(), layouts.next())
+            else {
+                continue;
+            };
+
+            children[0].as_widget().draw(
+                &trees[0],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: navigation::icon_color(theme, is_selected),
+                },
+                icon,
+                cursor,
+                viewport,
+            );
+
+            children[1].as_widget().draw(
+                &trees[1],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: match self.mode {
+                        Mode::Collapsed => {
+                            navigation::label_color(theme, is_selected)
+                        }
+                        Mode::Expanded => {
+                            navigation::icon_color(theme, is_selected)
+                        }
+                    },
+                },
+                label,
+                cursor,
+                viewport,
+            );
+
+            if let (Some(badge), Some(layout)) =
+                (children.get(2), layouts.next())
+            {
+                badge.as_widget().draw(
+                    &trees[2],
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        text_color: navigation::icon_color(theme, is_selected),
+                    },
+                    layout,
+                    cursor,
+                    viewport,
+                );
+            } else if let Some(badge) = self.badges[index] {
+                navigation::draw_badge(
+                    renderer,
+                    theme,
+                    badge,
+                    icon.bounds(),
+                    viewport,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.children[..self.header],
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
@@ -0,0 +1,222 @@
+//! Per-theme component overrides.
+//!
+//! A [`Custom`](crate::Custom) theme can carry [`Overrides`] that tweak the
+//! default style of a component, e.g. togglers using the tertiary color or
+//! containers without rounded corners, without having to write a whole style
+//! function.
+use iced_widget::core::{self, Color, Shadow};
+
+use crate::utils::State;
+use crate::{Role, Theme};
+
+/// A component whose default style can be overridden.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Component {
+    Button,
+    Checkbox,
+    Container,
+    Menu,
+    PickList,
+    ProgressBar,
+    Radio,
+    Slider,
+    TextEditor,
+    TextInput,
+    Toggler,
+}
+
+/// A partial [`Border`](core::Border). Fields left as `None` keep their
+/// default value.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Border {
+    #[cfg_attr(feature = "serde", serde(with = "crate::color_serde::option"))]
+    pub color: Option<Color>,
+    pub width: Option<f32>,
+    pub radius: Option<f32>,
+}
+
+impl Border {
+    /// Applies the [`Border`] on top of the given one.
+    pub fn apply(&self, border: core::Border) -> core::Border {
+        core::Border {
+            color: self.color.unwrap_or(border.color),
+            width: self.width.unwrap_or(border.width),
+            radius: self
+                .radius
+                .map_or(border.radius, core::border::Radius::from),
+        }
+    }
+}
+
+/// The overridden properties of a component's style.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Override {
+    /// The color [`Role`] of the component.
+    ///
+    /// Ignored by components without an accent color, like containers, menus
+    /// and pick lists.
+    pub role: Option<Role>,
+    pub border: Option<Border>,
+    /// The elevation level of the component's shadow.
+    pub shadow: Option<u8>,
+}
+
+impl Override {
+    /// An [`Override`] that changes nothing.
+    pub const NONE: Self = Self {
+        role: None,
+        border: None,
+        shadow: None,
+    };
+
+    /// Returns the [`Role`] of the [`Override`], or the given default.
+    pub fn role_or(&self, role: Role) -> Role {
+        self.role.unwrap_or(role)
+    }
+
+    /// Applies the overridden border, if any, on top of the given one.
+    pub fn border(&self, border: core::Border) -> core::Border {
+        self.border.map_or(border, |b| b.apply(border))
+    }
+
+    /// Returns the overridden shadow, if any, or the given one.
+    pub fn shadow(&self, theme: &Theme, shadow: Shadow) -> Shadow {
+        self.shadow.map_or(shadow, |level| {
+            theme.elevation().shadow(level, theme.colors().shadow)
+        })
+    }
+
+    /// Merges two [`Override`]s, preferring the properties of `other`.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            role: other.role.or(self.role),
+            border: other.border.or(self.border),
+            shadow: other.shadow.or(self.shadow),
+        }
+    }
+}
+
+/// A single entry of the [`Overrides`] table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Entry {
+    pub component: Component,
+    /// The state the [`Override`] applies to, or `None` for all of them.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub status: Option<State>,
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub style: Override,
+}
+
+/// A table of component overrides.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Overrides {
+    pub entries: Vec<Entry>,
+}
+
+impl Overrides {
+    /// An empty table.
+    pub const DEFAULT: Self = Self {
+        entries: Vec::new(),
+    };
+
+    /// Adds an [`Override`] for the given component in every state.
+    pub fn with(self, component: Component, style: Override) -> Self {
+        self.push(component, None, style)
+    }
+
+    /// Adds an [`Override`] for the given component in the given state.
+    pub fn with_status(
+        self,
+        component: Component,
+        status: State,
+        style: Override,
+    ) -> Self {
+        self.push(component, Some(status), style)
+    }
+
+    fn push(
+        mut self,
+        component: Component,
+        status: Option<State>,
+        style: Override,
+    ) -> Self {
+        self.entries.push(Entry {
+            component,
+            status,
+            style,
+        });
+        self
+    }
+
+    /// Returns the [`Override`] of the component in the given state.
+    ///
+    /// Entries for a specific state take precedence over the ones for every
+    /// state, and later entries over earlier ones.
+    pub fn get(&self, component: Component, status: State) -> Override {
+        let matching = |specific: bool| {
+            self.entries.iter().filter(move |entry| {
+                entry.component == component
+                    && if specific {
+                        entry.status == Some(status)
+                    } else {
+                        entry.status.is_none()
+                    }
+            })
+        };
+
+        matching(false)
+            .chain(matching(true))
+            .fold(Override::NONE, |style, entry| style.merge(entry.style))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Component, Override, Overrides};
+    use crate::Role;
+    use crate::utils::State;
+
+    #[test]
+    fn specific_status_takes_precedence() {
+        let overrides = Overrides::DEFAULT
+            .with_status(
+                Component::Toggler,
+                State::Hovered,
+                Override {
+                    role: Some(Role::Error),
+                    ..Override::NONE
+                },
+            )
+            .with(
+                Component::Toggler,
+                Override {
+                    role: Some(Role::Tertiary),
+                    shadow: Some(1),
+                    ..Override::NONE
+                },
+            );
+
+        let enabled = overrides.get(Component::Toggler, State::Enabled);
+        let hovered = overrides.get(Component::Toggler, State::Hovered);
+
+        assert_eq!(enabled.role, Some(Role::Tertiary));
+        assert_eq!(hovered.role, Some(Role::Error));
+        assert_eq!(hovered.shadow, Some(1));
+        assert_eq!(
+            overrides.get(Component::Slider, State::Enabled),
+            Override::NONE
+        );
+    }
+}
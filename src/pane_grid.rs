@@ -2,29 +2,40 @@ use iced_widget::core::{Background, border};
 use iced_widget::pane_grid::{Catalog, Highlight, Line, Style, StyleFn};
 
 use super::Theme;
-use crate::utils::{HOVERED_LAYER_OPACITY, mix};
+use crate::class::style_class;
+use crate::utils::State;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> <Self as Catalog>::Class<'a> {
-        Box::new(default)
+        Class::Default
     }
 
     fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style {
-        class(self)
+        class.style(self)
+    }
+}
+
+style_class! {
+    /// The named styles of a pane grid.
+    () -> Style {
+        Default => default,
     }
 }
 
 pub fn default(theme: &Theme) -> Style {
+    let (hovered_region, _) = theme.state_layer().compose(
+        theme.colors().tertiary.tertiary_container,
+        theme.colors().surface.on_surface,
+        theme.colors().surface.on_surface,
+        State::Hovered,
+    );
+
     Style {
         hovered_region: Highlight {
-            background: Background::Color(mix(
-                theme.colors().tertiary.tertiary_container,
-                theme.colors().surface.on_surface,
-                HOVERED_LAYER_OPACITY,
-            )),
-            border: border::rounded(12),
+            background: Background::Color(hovered_region),
+            border: border::rounded(theme.shapes().medium),
         },
         picked_split: Line {
             color: theme.colors().outline.variant,
@@ -1,13 +1,16 @@
-use iced_widget::core::{Background, border};
+use iced_widget::core::{Background, Border, border};
 use iced_widget::pick_list::{Catalog, Status, Style, StyleFn};
 
 use super::Theme;
+use crate::class::style_class;
+use crate::overrides::Component;
+use crate::utils::State;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> <Self as Catalog>::Class<'a> {
-        Box::new(default)
+        Class::Default
     }
 
     fn style(
@@ -15,11 +18,40 @@ impl Catalog for Theme {
         class: &<Self as Catalog>::Class<'_>,
         status: Status,
     ) -> Style {
-        class(self, status)
+        class.style(self, status)
+    }
+}
+
+style_class! {
+    /// The named styles of a pick list.
+    (status: Status) -> Style {
+        Default => default,
+        Error => error,
+    }
+}
+
+impl From<Status> for State {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Active => Self::Enabled,
+            Status::Hovered => Self::Hovered,
+            Status::Opened { .. } => Self::Focused,
+        }
     }
 }
 
 pub fn default(theme: &Theme, status: Status) -> Style {
+    let overrides =
+        theme.component_override(Component::PickList, status.into());
+    let style = base(theme, status);
+
+    Style {
+        border: overrides.border(style.border),
+        ..style
+    }
+}
+
+fn base(theme: &Theme, status: Status) -> Style {
     let surface = theme.colors().surface;
 
     let active = Style {
@@ -27,7 +59,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         placeholder_color: surface.on_surface_variant,
         handle_color: surface.on_surface_variant,
         background: Background::Color(surface.surface_container.highest),
-        border: border::rounded(4),
+        border: border::rounded(theme.shapes().extra_small),
     };
 
     match status {
@@ -38,8 +70,30 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         },
         Status::Opened { .. } => Style {
             background: Background::Color(surface.surface_container.highest),
-            border: border::rounded(4),
+            border: border::rounded(theme.shapes().extra_small),
             ..active
         },
     }
 }
+
+pub fn error(theme: &Theme, status: Status) -> Style {
+    let error = theme.colors().error;
+    let style = base(theme, status);
+
+    let (color, width) = match status {
+        Status::Active => (error.color, 1.0),
+        Status::Hovered => (error.on_error_container, 1.0),
+        Status::Opened { .. } => (error.color, 2.0),
+    };
+
+    Style {
+        handle_color: error.color,
+        placeholder_color: error.color,
+        border: Border {
+            color,
+            width,
+            ..style.border
+        },
+        ..style
+    }
+}
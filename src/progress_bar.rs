@@ -1,26 +1,65 @@
-use iced_widget::core::{Background, border};
+use iced_widget::core::{Background, Color, border};
 use iced_widget::progress_bar::{Catalog, Style, StyleFn};
 
-use super::Theme;
+use super::{Role, Theme};
+use crate::class::style_class;
+use crate::overrides::Component;
+use crate::utils::State;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        Class::Default
     }
 
     fn style(&self, class: &Self::Class<'_>) -> Style {
-        class(self)
+        class.style(self)
     }
 }
 
-pub fn default(theme: &Theme) -> Style {
+style_class! {
+    /// The named styles of a progress bar.
+    () -> Style {
+        Default => default,
+    }
+}
+
+pub fn styled(theme: &Theme, background: Color, bar: Color) -> Style {
     Style {
-        background: Background::Color(
-            theme.colors().secondary.secondary_container,
+        background: Background::Color(background),
+        bar: Background::Color(bar),
+        border: border::rounded(theme.shapes().full),
+    }
+}
+
+pub fn default(theme: &Theme) -> Style {
+    let colors = theme.colors();
+    let overrides =
+        theme.component_override(Component::ProgressBar, State::Enabled);
+
+    let style = match overrides.role {
+        Some(role) => styled_with(role)(theme),
+        None => styled(
+            theme,
+            colors.secondary.secondary_container,
+            colors.primary.color,
         ),
-        bar: Background::Color(theme.colors().primary.color),
-        border: border::rounded(400),
+    };
+
+    Style {
+        border: overrides.border(style.border),
+        ..style
+    }
+}
+
+/// A progress bar colored with the given [`Role`].
+///
+/// The bar uses the color of the [`Role`], and the track its container color.
+pub fn styled_with(role: Role) -> impl Fn(&Theme) -> Style {
+    move |theme| {
+        let group = theme.colors().role(role);
+
+        styled(theme, group.container, group.color)
     }
 }
@@ -1,16 +1,24 @@
 use iced_widget::qr_code::{Catalog, Style, StyleFn};
 
 use super::Theme;
+use crate::class::style_class;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        Class::Default
     }
 
     fn style(&self, class: &Self::Class<'_>) -> Style {
-        class(self)
+        class.style(self)
+    }
+}
+
+style_class! {
+    /// The named styles of a QR code.
+    () -> Style {
+        Default => default,
     }
 }
 
@@ -1,62 +1,115 @@
-use iced_widget::core::{Background, Color};
+use iced_widget::core::{Background, Border, Color};
 use iced_widget::radio::{Catalog, Status, Style, StyleFn};
 
-use super::Theme;
-use crate::utils::{HOVERED_LAYER_OPACITY, disabled_text, mix};
+use super::{Role, Theme};
+use crate::class::style_class;
+use crate::overrides::Component;
+use crate::utils::State;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        Class::Default
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.style(self, status)
+    }
+}
+
+style_class! {
+    /// The named styles of a radio.
+    (status: Status) -> Style {
+        Default => default,
+        Error => error,
+    }
+}
+
+impl From<Status> for State {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Active { .. } => Self::Enabled,
+            Status::Hovered { .. } => Self::Hovered,
+        }
     }
 }
 
 pub fn default(theme: &Theme, status: Status) -> Style {
+    let overrides = theme.component_override(Component::Radio, status.into());
+    let style = styled_with(overrides.role_or(Role::Primary))(theme, status);
+
+    let border = overrides.border(Border {
+        color: style.border_color,
+        width: style.border_width,
+        ..Border::default()
+    });
+
+    Style {
+        border_color: border.color,
+        border_width: border.width,
+        ..style
+    }
+}
+
+pub fn styled_with(role: Role) -> impl Fn(&Theme, Status) -> Style {
+    move |theme, status| {
+        let colors = theme.colors();
+
+        colored(
+            theme,
+            colors.role(role).color,
+            colors.surface.on_surface,
+            status,
+        )
+    }
+}
+
+pub fn error(theme: &Theme, status: Status) -> Style {
+    let error = theme.colors().error;
+
+    Style {
+        text_color: Some(error.color),
+        ..colored(theme, error.color, error.color, status)
+    }
+}
+
+fn colored(
+    theme: &Theme,
+    color: Color,
+    outline: Color,
+    status: Status,
+) -> Style {
     let surface = theme.colors().surface;
-    let primary = theme.colors().primary;
+    let state_layer = theme.state_layer();
 
-    let active = Style {
-        background: Color::TRANSPARENT.into(),
-        dot_color: primary.color,
+    let (Status::Active { is_selected } | Status::Hovered { is_selected }) =
+        status;
+    let state = State::from(status);
+
+    let (dot_color, _) = state_layer.compose(
+        color,
+        surface.on_surface,
+        surface.on_surface,
+        state,
+    );
+
+    let (background, _) = state_layer.compose(
+        Color::TRANSPARENT,
+        if is_selected {
+            color
+        } else {
+            surface.on_surface
+        },
+        surface.on_surface,
+        state,
+    );
+
+    Style {
+        background: Background::Color(background),
+        dot_color,
         border_width: 1.0,
-        border_color: primary.color,
+        border_color: if is_selected { dot_color } else { outline },
         text_color: None,
-    };
-
-    match status {
-        Status::Active { is_selected } => Style {
-            border_color: if is_selected {
-                active.border_color
-            } else {
-                surface.on_surface
-            },
-            ..active
-        },
-        Status::Hovered { is_selected } => Style {
-            dot_color: mix(
-                primary.color,
-                surface.on_surface,
-                HOVERED_LAYER_OPACITY,
-            ),
-            border_color: if is_selected {
-                mix(primary.color, surface.on_surface, HOVERED_LAYER_OPACITY)
-            } else {
-                disabled_text(surface.on_surface)
-            },
-            background: Background::Color(if is_selected {
-                Color {
-                    a: HOVERED_LAYER_OPACITY,
-                    ..surface.on_surface
-                }
-            } else {
-                Color::TRANSPARENT
-            }),
-            ..active
-        },
     }
 }
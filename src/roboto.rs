@@ -0,0 +1,91 @@
+//! The bundled [Roboto](https://fonts.google.com/specimen/Roboto) font family.
+//!
+//! The Material 3 type scale only uses the [`REGULAR`] and [`MEDIUM`]
+//! weights, but every static weight of the family is bundled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced_material::{Theme, roboto};
+//!
+//! fn main() -> iced::Result {
+//!     iced::application(State::default, State::update, State::view)
+//!         .settings(roboto::settings(iced::Settings::default()))
+//!         .theme(|_: &State| Theme::Dark)
+//!         .run()
+//! }
+//!
+//! #[derive(Debug, Default)]
+//! struct State;
+//!
+//! impl State {
+//!     fn update(&mut self, _message: ()) {}
+//!
+//!     fn view(&self) -> iced::Element<(), Theme> {
+//!         iced::widget::text("Hello!").into()
+//!     }
+//! }
+//! ```
+use iced_widget::core::Settings;
+use iced_widget::core::font::{Family, Font, Weight};
+use iced_widget::runtime::Task;
+use iced_widget::runtime::font::{self, Error};
+
+/// The name of the font family.
+pub const NAME: &str = "Roboto";
+
+/// Roboto Thin, with a weight of 100.
+pub const THIN: Font = font(Weight::Thin);
+/// Roboto Light, with a weight of 300.
+pub const LIGHT: Font = font(Weight::Light);
+/// Roboto Regular, with a weight of 400.
+pub const REGULAR: Font = font(Weight::Normal);
+/// Roboto Medium, with a weight of 500.
+pub const MEDIUM: Font = font(Weight::Medium);
+/// Roboto Bold, with a weight of 700.
+pub const BOLD: Font = font(Weight::Bold);
+/// Roboto Black, with a weight of 900.
+pub const BLACK: Font = font(Weight::Black);
+
+/// The [`Font`] meant to be used as the default font of an application.
+pub const DEFAULT: Font = REGULAR;
+
+/// The bytes of every bundled font file.
+pub const FONTS: &[&[u8]] = &[
+    include_bytes!("../assets/fonts/Roboto-Thin.ttf"),
+    include_bytes!("../assets/fonts/Roboto-Light.ttf"),
+    include_bytes!("../assets/fonts/Roboto-Regular.ttf"),
+    include_bytes!("../assets/fonts/Roboto-Medium.ttf"),
+    include_bytes!("../assets/fonts/Roboto-Bold.ttf"),
+    include_bytes!("../assets/fonts/Roboto-Black.ttf"),
+];
+
+/// Returns the Roboto [`Font`] with the given [`Weight`].
+pub const fn font(weight: Weight) -> Font {
+    Font {
+        family: Family::Name(NAME),
+        weight,
+        ..Font::DEFAULT
+    }
+}
+
+/// Adds the bundled [`FONTS`] to the given [`Settings`] and makes
+/// [`DEFAULT`] the default font.
+pub fn settings(settings: Settings) -> Settings {
+    let mut fonts = settings.fonts;
+    fonts.extend(FONTS.iter().map(|bytes| (*bytes).into()));
+
+    Settings {
+        fonts,
+        default_font: DEFAULT,
+        ..settings
+    }
+}
+
+/// Loads every bundled font file at runtime.
+///
+/// Prefer [`settings`] when possible, so the fonts are available for the
+/// first frame. The default font can't be changed at runtime.
+pub fn load() -> Task<Result<(), Error>> {
+    Task::batch(FONTS.iter().map(|bytes| font::load(*bytes)))
+}
@@ -2,16 +2,25 @@ use iced_widget::core::border::Radius;
 use iced_widget::rule::{Catalog, FillMode, Style, StyleFn};
 
 use super::Theme;
+use crate::class::style_class;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(inset)
+        Class::Inset
     }
 
     fn style(&self, class: &Self::Class<'_>) -> Style {
-        class(self)
+        class.style(self)
+    }
+}
+
+style_class! {
+    /// The named styles of a rule.
+    () -> Style {
+        Inset => inset,
+        FullWidth => full_width,
     }
 }
 
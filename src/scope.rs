@@ -0,0 +1,173 @@
+//! Rendering a subtree of the UI with another [`Theme`].
+//!
+//! M3 designs often contain sections that use a different color scheme than
+//! the rest of the page, like a dark promo card inside a light page or an
+//! error banner. Wrapping such a section with [`scoped`] makes every widget
+//! inside it style itself against the given [`Theme`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced_material::scope::scoped;
+//! use iced_material::{Role, Theme};
+//! use iced_widget::{button, column, text};
+//!
+//! # fn view<'a>(theme: &Theme) -> iced_widget::core::Element<'a, (), Theme, iced_widget::Renderer> {
+//! let promo = scoped(
+//!     theme.inverse(),
+//!     column![text("Get the app"), button("Install").on_press(())],
+//! );
+//!
+//! let banner = scoped(
+//!     theme.map_colors(|colors| colors.for_container(Role::Error)),
+//!     text("Something went wrong"),
+//! );
+//!
+//! column![promo, banner].into()
+//! # }
+//! ```
+use iced_widget::Themer;
+use iced_widget::core::{self, Color, Element};
+
+use crate::utils::{lightness, mix};
+use crate::{
+    ColorGroup, ColorScheme, Custom, Error, Inverse, Primary, Role, Secondary,
+    Surface, SurfaceContainer, Tertiary, Theme,
+};
+
+/// Renders the content with the given [`Theme`].
+///
+/// Plain text inside the content uses the `on_surface` color of the
+/// [`Theme`].
+pub fn scoped<'a, Message, Renderer>(
+    theme: impl Into<Theme>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Themer<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Themer::new(Some(theme.into()), content)
+        .text_color(|theme| theme.colors().surface.on_surface)
+}
+
+impl Theme {
+    /// Applies the given transform to the [`ColorScheme`] of the [`Theme`],
+    /// keeping the rest of its tokens.
+    pub fn map_colors(
+        &self,
+        f: impl FnOnce(ColorScheme) -> ColorScheme,
+    ) -> Self {
+        let colorscheme = f(self.colors());
+
+        Self::Custom(Custom {
+            is_dark: lightness(colorscheme.surface.color) <= 0.5,
+            colorscheme,
+            ..self.clone().into()
+        })
+    }
+
+    /// Returns the [`Theme`] with the inverse [`ColorScheme`].
+    ///
+    /// See [`ColorScheme::inverse`].
+    pub fn inverse(&self) -> Self {
+        self.map_colors(|colors| colors.inverse())
+    }
+}
+
+impl ColorScheme {
+    /// Returns the inverse [`ColorScheme`], where the surface uses the
+    /// inverse surface colors.
+    ///
+    /// The accent colors are swapped with their container counterparts, which
+    /// approximates the tones of the opposite (light or dark) scheme.
+    pub fn inverse(&self) -> Self {
+        let swap = |role| {
+            let group = self.role(role);
+
+            ColorGroup {
+                color: group.container,
+                on_color: group.on_container,
+                container: group.on_container,
+                on_container: group.container,
+            }
+        };
+
+        let primary = swap(Role::Primary);
+        let secondary = swap(Role::Secondary);
+        let tertiary = swap(Role::Tertiary);
+        let error = swap(Role::Error);
+
+        Self {
+            primary: Primary {
+                color: self.inverse.inverse_primary,
+                on_primary: primary.on_color,
+                primary_container: primary.container,
+                on_primary_container: primary.on_container,
+            },
+            secondary: Secondary {
+                color: secondary.color,
+                on_secondary: secondary.on_color,
+                secondary_container: secondary.container,
+                on_secondary_container: secondary.on_container,
+            },
+            tertiary: Tertiary {
+                color: tertiary.color,
+                on_tertiary: tertiary.on_color,
+                tertiary_container: tertiary.container,
+                on_tertiary_container: tertiary.on_container,
+            },
+            error: Error {
+                color: error.color,
+                on_error: error.on_color,
+                error_container: error.container,
+                on_error_container: error.on_container,
+            },
+            surface: surface(
+                self.inverse.inverse_surface,
+                self.inverse.inverse_on_surface,
+            ),
+            inverse: Inverse {
+                inverse_surface: self.surface.color,
+                inverse_on_surface: self.surface.on_surface,
+                inverse_primary: self.primary.color,
+            },
+            ..*self
+        }
+    }
+
+    /// Returns a [`ColorScheme`] for content placed on the container color of
+    /// the given [`Role`], like an error banner.
+    ///
+    /// The surface uses the container colors of the [`Role`], and its colors
+    /// become the primary ones.
+    pub fn for_container(&self, role: Role) -> Self {
+        let group = self.role(role);
+
+        Self {
+            primary: Primary {
+                color: group.color,
+                on_primary: group.on_color,
+                primary_container: group.container,
+                on_primary_container: group.on_container,
+            },
+            surface: surface(group.container, group.on_container),
+            ..*self
+        }
+    }
+}
+
+/// Derives the surface colors from a background and its content color.
+fn surface(color: Color, on_surface: Color) -> Surface {
+    Surface {
+        color,
+        on_surface,
+        on_surface_variant: mix(on_surface, color, 0.2),
+        surface_container: SurfaceContainer {
+            lowest: color,
+            low: mix(color, on_surface, 0.04),
+            base: mix(color, on_surface, 0.08),
+            high: mix(color, on_surface, 0.11),
+            highest: mix(color, on_surface, 0.14),
+        },
+    }
+}
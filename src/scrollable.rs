@@ -5,43 +5,59 @@ use iced_widget::scrollable::{
 
 use super::Theme;
 use super::container::surface_container;
-use super::utils::mix;
-use crate::utils::{
-    HOVERED_LAYER_OPACITY, PRESSED_LAYER_OPACITY, disabled_container,
-    disabled_text,
-};
+use crate::class::style_class;
+use crate::utils::State;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        Class::Default
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.style(self, status)
+    }
+}
+
+style_class! {
+    /// The named styles of a scrollable.
+    (status: Status) -> Style {
+        Default => default,
     }
 }
 
 pub fn default(theme: &Theme, status: Status) -> Style {
     let surface = theme.colors().surface;
+    let state_layer = theme.state_layer();
+
+    let scroller = |state| {
+        state_layer
+            .compose(
+                surface.on_surface,
+                surface.color,
+                surface.on_surface,
+                state,
+            )
+            .0
+    };
 
     let active = Rail {
         background: None,
         scroller: Scroller {
             color: surface.on_surface,
-            border: border::rounded(400),
+            border: border::rounded(theme.shapes().full),
         },
         border: Border::default(),
     };
 
     let disabled = Rail {
-        background: Some(Background::Color(disabled_container(
-            surface.on_surface,
-        ))),
+        background: Some(Background::Color(
+            state_layer.disabled_container(surface.on_surface),
+        )),
         scroller: Scroller {
-            color: disabled_text(surface.on_surface),
-            border: border::rounded(400),
+            color: state_layer.disabled_content(surface.on_surface),
+            border: border::rounded(theme.shapes().full),
         },
         ..active
     };
@@ -78,12 +94,8 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         } => {
             let hovered_rail = Rail {
                 scroller: Scroller {
-                    color: mix(
-                        surface.on_surface,
-                        surface.color,
-                        HOVERED_LAYER_OPACITY,
-                    ),
-                    border: border::rounded(400),
+                    color: scroller(State::Hovered),
+                    border: border::rounded(theme.shapes().full),
                 },
                 ..active
             };
@@ -114,12 +126,8 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         } => {
             let dragged_rail = Rail {
                 scroller: Scroller {
-                    color: mix(
-                        surface.on_surface,
-                        surface.color,
-                        PRESSED_LAYER_OPACITY,
-                    ),
-                    border: border::rounded(400),
+                    color: scroller(State::Dragged),
+                    border: border::rounded(theme.shapes().full),
                 },
                 ..active
             };
@@ -0,0 +1,291 @@
+//! [Segmented buttons](https://m3.material.io/components/segmented-buttons/overview).
+//!
+//! A segmented button is a row of joined segments sharing a single outline,
+//! used to pick one option (like "Day / Week / Month") or toggle several
+//! ones. Selected segments use the secondary container color and show a
+//! checkmark.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced_material::Theme;
+//! use iced_material::segmented_button::{self, Segment};
+//! use iced_widget::text;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum View {
+//!     Day,
+//!     Week,
+//!     Month,
+//! }
+//!
+//! # fn view<'a>(theme: &Theme, current: View) -> iced_widget::core::Element<'a, View, Theme, iced_widget::Renderer> {
+//! segmented_button::single(
+//!     theme,
+//!     [
+//!         Segment::new(View::Day).label(text("Day")),
+//!         Segment::new(View::Week).label(text("Week")),
+//!         Segment::new(View::Month).label(text("Month")),
+//!     ],
+//!     Some(current),
+//!     |view| view,
+//! )
+//! .into()
+//! # }
+//! ```
+use std::fmt;
+
+use iced_widget::button::{Status, Style};
+use iced_widget::core::border::{self, Radius};
+use iced_widget::core::text::LineHeight;
+use iced_widget::core::{
+    self, Alignment, Background, Border, Color, Element, Length, Padding,
+};
+use iced_widget::{Button, Container, Row, Space, Text, container};
+
+use crate::Theme;
+
+/// The height of a segmented button at density `0`, in logical pixels.
+pub const HEIGHT: f32 = 40.0;
+
+/// A segment of a segmented button.
+pub struct Segment<'a, T, Message, Renderer = iced_widget::Renderer> {
+    value: T,
+    icon: Option<Element<'a, Message, Theme, Renderer>>,
+    label: Option<Element<'a, Message, Theme, Renderer>>,
+    enabled: bool,
+}
+
+impl<'a, T, Message, Renderer> Segment<'a, T, Message, Renderer> {
+    /// Creates an empty [`Segment`] with the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            icon: None,
+            label: None,
+            enabled: true,
+        }
+    }
+
+    /// Sets the icon of the [`Segment`].
+    ///
+    /// When the [`Segment`] also has a label, the icon is replaced by a
+    /// checkmark while selected.
+    pub fn icon(
+        self,
+        icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            icon: Some(icon.into()),
+            ..self
+        }
+    }
+
+    /// Sets the label of the [`Segment`].
+    pub fn label(
+        self,
+        label: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            label: Some(label.into()),
+            ..self
+        }
+    }
+
+    /// Sets whether the [`Segment`] can be interacted with.
+    pub fn enabled(self, enabled: bool) -> Self {
+        Self { enabled, ..self }
+    }
+}
+
+impl<T, Message, Renderer> fmt::Debug for Segment<'_, T, Message, Renderer>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Segment")
+            .field("value", &self.value)
+            .field("enabled", &self.enabled)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Creates a segmented button where at most one segment is selected.
+pub fn single<'a, T, Message, Renderer>(
+    theme: &Theme,
+    segments: impl IntoIterator<Item = Segment<'a, T, Message, Renderer>>,
+    selected: Option<T>,
+    on_select: impl Fn(T) -> Message,
+) -> Container<'a, Message, Theme, Renderer>
+where
+    T: Clone + PartialEq,
+    Message: Clone + 'a,
+    Renderer: core::text::Renderer + 'a,
+{
+    segmented(theme, segments, |value| {
+        (selected.as_ref() == Some(value), on_select(value.clone()))
+    })
+}
+
+/// Creates a segmented button where any number of segments can be selected.
+///
+/// `on_toggle` receives the value of a segment and whether it should become
+/// selected.
+pub fn multiple<'a, T, Message, Renderer>(
+    theme: &Theme,
+    segments: impl IntoIterator<Item = Segment<'a, T, Message, Renderer>>,
+    selected: &[T],
+    on_toggle: impl Fn(T, bool) -> Message,
+) -> Container<'a, Message, Theme, Renderer>
+where
+    T: Clone + PartialEq,
+    Message: Clone + 'a,
+    Renderer: core::text::Renderer + 'a,
+{
+    segmented(theme, segments, |value| {
+        let is_selected = selected.contains(value);
+
+        (is_selected, on_toggle(value.clone(), !is_selected))
+    })
+}
+
+fn segmented<'a, T, Message, Renderer>(
+    theme: &Theme,
+    segments: impl IntoIterator<Item = Segment<'a, T, Message, Renderer>>,
+    state: impl Fn(&T) -> (bool, Message),
+) -> Container<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: core::text::Renderer + 'a,
+{
+    let height = theme.sizing().density.apply(HEIGHT);
+    let full = theme.shapes().full;
+
+    let segments: Vec<_> = segments.into_iter().collect();
+    let last = segments.len().saturating_sub(1);
+
+    let row = segments.into_iter().enumerate().fold(
+        Row::new().height(height - 2.0),
+        |row, (index, segment)| {
+            let (is_selected, message) = state(&segment.value);
+
+            let radius = match (index == 0, index == last) {
+                (true, true) => border::radius(full),
+                (true, false) => border::left(full),
+                (false, true) => border::right(full),
+                (false, false) => Radius::default(),
+            };
+
+            let checkmark = || {
+                Text::new(Renderer::CHECKMARK_ICON.to_string())
+                    .font(Renderer::ICON_FONT)
+                    .size(18)
+                    .line_height(LineHeight::Relative(1.0))
+                    .into()
+            };
+
+            let icon = match (segment.icon, &segment.label) {
+                (Some(_), Some(_)) | (None, _) if is_selected => {
+                    Some(checkmark())
+                }
+                (icon, _) => icon,
+            };
+
+            let content = Row::new()
+                .push(icon)
+                .push(segment.label)
+                .spacing(8)
+                .align_y(Alignment::Center);
+
+            let button =
+                Button::new(Container::new(content).center(Length::Fill))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .padding(Padding::from([0, 12]))
+                    .on_press_maybe(segment.enabled.then_some(message))
+                    .style(styled_with(is_selected, radius));
+
+            let row = if index == 0 {
+                row
+            } else {
+                row.push(
+                    Container::new(Space::new())
+                        .width(1)
+                        .height(Length::Fill)
+                        .style(divider),
+                )
+            };
+
+            row.push(button)
+        },
+    );
+
+    Container::new(row).padding(1).style(outline).height(height)
+}
+
+/// The outline around a segmented button.
+pub fn outline(theme: &Theme) -> container::Style {
+    container::Style {
+        border: Border {
+            color: theme.colors().outline.color,
+            width: 1.0,
+            radius: theme.shapes().full.into(),
+        },
+        ..container::Style::default()
+    }
+}
+
+/// The divider between two segments.
+pub fn divider(theme: &Theme) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(theme.colors().outline.color)),
+        ..container::Style::default()
+    }
+}
+
+/// A segment with the given corner [`Radius`].
+pub fn styled_with(
+    is_selected: bool,
+    radius: Radius,
+) -> impl Fn(&Theme, Status) -> Style + Copy {
+    move |theme, status| {
+        let colors = theme.colors();
+        let surface = colors.surface;
+
+        let (container, content) = if is_selected {
+            (
+                colors.secondary.secondary_container,
+                colors.secondary.on_secondary_container,
+            )
+        } else {
+            (Color::TRANSPARENT, surface.on_surface)
+        };
+
+        let state_layer = theme.state_layer();
+
+        let (background, text_color) = match status {
+            Status::Disabled => (
+                if is_selected {
+                    state_layer.disabled_container(surface.on_surface)
+                } else {
+                    Color::TRANSPARENT
+                },
+                state_layer.disabled_content(surface.on_surface),
+            ),
+            status => state_layer.compose(
+                container,
+                content,
+                surface.on_surface,
+                status.into(),
+            ),
+        };
+
+        Style {
+            background: Some(Background::Color(background)),
+            text_color,
+            border: border::rounded(radius),
+            snap: cfg!(feature = "crisp"),
+            ..Style::default()
+        }
+    }
+}
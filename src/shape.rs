@@ -0,0 +1,66 @@
+//! The Material 3 [shape scale](https://m3.material.io/styles/shape/corner-radius-scale).
+
+/// A step of the shape scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Shape {
+    /// Square corners.
+    #[default]
+    None,
+    ExtraSmall,
+    Small,
+    Medium,
+    Large,
+    ExtraLarge,
+    /// Fully rounded corners.
+    Full,
+}
+
+/// A [`Theme`]'s corner radii, in logical pixels.
+///
+/// [`Theme`]: crate::Theme
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Shapes {
+    pub extra_small: f32,
+    pub small: f32,
+    pub medium: f32,
+    pub large: f32,
+    pub extra_large: f32,
+    /// The radius used for fully rounded corners.
+    ///
+    /// It should be large enough to turn any component into a pill.
+    pub full: f32,
+}
+
+impl Shapes {
+    /// The default Material 3 shape scale.
+    pub const DEFAULT: Self = Self {
+        extra_small: 4.0,
+        small: 8.0,
+        medium: 12.0,
+        large: 16.0,
+        extra_large: 28.0,
+        full: 400.0,
+    };
+
+    /// Returns the corner radius of the given [`Shape`].
+    pub fn radius(&self, shape: Shape) -> f32 {
+        match shape {
+            Shape::None => 0.0,
+            Shape::ExtraSmall => self.extra_small,
+            Shape::Small => self.small,
+            Shape::Medium => self.medium,
+            Shape::Large => self.large,
+            Shape::ExtraLarge => self.extra_large,
+            Shape::Full => self.full,
+        }
+    }
+}
+
+impl Default for Shapes {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
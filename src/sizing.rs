@@ -0,0 +1,220 @@
+//! Component sizes and [density](https://m3.material.io/foundations/layout/understanding-layout/density).
+//!
+//! The helpers in this module create `iced` widgets with the padding and
+//! text size matching the [`Theme`]'s [`Sizing`].
+use std::borrow::Borrow;
+
+use iced_widget::core::text::{IntoFragment, LineHeight};
+use iced_widget::core::{self, Element, Padding, Pixels};
+use iced_widget::{Button, Checkbox, PickList, TextInput, Toggler};
+
+use crate::Theme;
+
+/// The density of a [`Theme`]. Each step makes components 4 pixels shorter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Density {
+    /// Density `0`.
+    #[default]
+    Default,
+    /// Density `-1`.
+    Comfortable,
+    /// Density `-2`.
+    Compact,
+    /// Density `-3`.
+    Dense,
+}
+
+impl Density {
+    pub const ALL: &'static [Self] =
+        &[Self::Default, Self::Comfortable, Self::Compact, Self::Dense];
+
+    /// The density scale, from `0` to `-3`.
+    pub fn scale(self) -> i8 {
+        match self {
+            Self::Default => 0,
+            Self::Comfortable => -1,
+            Self::Compact => -2,
+            Self::Dense => -3,
+        }
+    }
+
+    /// Applies the [`Density`] to the given height.
+    pub fn apply(self, height: f32) -> f32 {
+        height + f32::from(self.scale()) * 4.0
+    }
+}
+
+/// A [`Theme`]'s component sizes, in logical pixels, at density `0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Sizing {
+    pub density: Density,
+    pub button_height: f32,
+    pub button_padding: f32,
+    pub text_field_height: f32,
+    pub text_field_padding: f32,
+    pub list_item_height: f32,
+    pub checkbox: f32,
+    pub toggler: f32,
+    /// The spacing between a control and its label.
+    pub label_spacing: f32,
+}
+
+impl Sizing {
+    /// The default Material 3 component sizes.
+    pub const DEFAULT: Self = Self {
+        density: Density::Default,
+        button_height: 40.0,
+        button_padding: 24.0,
+        text_field_height: 56.0,
+        text_field_padding: 16.0,
+        list_item_height: 56.0,
+        checkbox: 18.0,
+        toggler: 32.0,
+        label_spacing: 12.0,
+    };
+
+    /// The height of a button, with the [`Density`] applied.
+    pub fn button_height(&self) -> f32 {
+        self.density.apply(self.button_height)
+    }
+
+    /// The height of a text field, with the [`Density`] applied.
+    pub fn text_field_height(&self) -> f32 {
+        self.density.apply(self.text_field_height)
+    }
+
+    /// The height of a one-line list item, with the [`Density`] applied.
+    pub fn list_item_height(&self) -> f32 {
+        self.density.apply(self.list_item_height)
+    }
+
+    /// The spacing between a control and its label, with the [`Density`]
+    /// applied.
+    pub fn label_spacing(&self) -> f32 {
+        (self.label_spacing + f32::from(self.density.scale()) * 2.0).max(0.0)
+    }
+}
+
+impl Default for Sizing {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Returns the [`Padding`] that centers a line of the given height inside a
+/// component of the given height.
+pub(crate) fn padding(
+    height: f32,
+    line_height: f32,
+    horizontal: f32,
+) -> Padding {
+    Padding::from([((height - line_height) / 2.0).max(0.0), horizontal])
+}
+
+/// Creates a [`Button`] sized for the [`Theme`].
+pub fn button<'a, Message, Renderer>(
+    theme: &Theme,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Button<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    let sizing = theme.sizing();
+    let label = theme.typography().label_large;
+
+    Button::new(content).padding(padding(
+        sizing.button_height(),
+        label.line_height,
+        sizing.button_padding,
+    ))
+}
+
+/// Creates a [`TextInput`] sized for the [`Theme`].
+pub fn text_input<'a, Message, Renderer>(
+    theme: &Theme,
+    placeholder: &str,
+    value: &str,
+) -> TextInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: core::text::Renderer,
+{
+    let sizing = theme.sizing();
+    let body = theme.typography().body_large;
+
+    TextInput::new(placeholder, value)
+        .padding(padding(
+            sizing.text_field_height(),
+            body.line_height,
+            sizing.text_field_padding,
+        ))
+        .size(body.size)
+        .line_height(LineHeight::Absolute(Pixels(body.line_height)))
+}
+
+/// Creates a [`PickList`] sized for the [`Theme`].
+pub fn pick_list<'a, T, L, V, Message, Renderer>(
+    theme: &Theme,
+    options: L,
+    selected: Option<V>,
+    on_selected: impl Fn(T) -> Message + 'a,
+) -> PickList<'a, T, L, V, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone + 'a,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<T> + 'a,
+    Message: Clone,
+    Renderer: core::text::Renderer,
+{
+    let sizing = theme.sizing();
+    let body = theme.typography().body_large;
+
+    PickList::new(options, selected, on_selected)
+        .padding(padding(
+            sizing.text_field_height(),
+            body.line_height,
+            sizing.text_field_padding,
+        ))
+        .text_size(body.size)
+        .text_line_height(LineHeight::Absolute(Pixels(body.line_height)))
+}
+
+/// Creates a [`Checkbox`] sized for the [`Theme`].
+pub fn checkbox<'a, Message, Renderer>(
+    theme: &Theme,
+    label: impl IntoFragment<'a>,
+    is_checked: bool,
+) -> Checkbox<'a, Message, Theme, Renderer>
+where
+    Renderer: core::text::Renderer,
+{
+    let sizing = theme.sizing();
+    let body = theme.typography().body_medium;
+
+    Checkbox::new(label, is_checked)
+        .size(sizing.checkbox)
+        .spacing(sizing.label_spacing())
+        .text_size(body.size)
+        .text_line_height(LineHeight::Absolute(Pixels(body.line_height)))
+}
+
+/// Creates a [`Toggler`] sized for the [`Theme`].
+pub fn toggler<'a, Message, Renderer>(
+    theme: &Theme,
+    is_toggled: bool,
+) -> Toggler<'a, Message, Theme, Renderer>
+where
+    Renderer: core::text::Renderer,
+{
+    let sizing = theme.sizing();
+    let body = theme.typography().body_medium;
+
+    Toggler::new(is_toggled)
+        .size(sizing.toggler)
+        .spacing(sizing.label_spacing())
+        .text_size(body.size)
+        .text_line_height(LineHeight::Absolute(Pixels(body.line_height)))
+}
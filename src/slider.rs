@@ -3,14 +3,17 @@ use iced_widget::slider::{
     Catalog, Handle, HandleShape, Rail, Status, Style, StyleFn,
 };
 
-use super::Theme;
-use crate::utils::{HOVERED_LAYER_OPACITY, PRESSED_LAYER_OPACITY, mix};
+use super::{Role, Theme};
+use crate::class::style_class;
+use crate::overrides::Component;
+use crate::shape::Shapes;
+use crate::utils::State;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> <Self as Catalog>::Class<'a> {
-        Box::new(default)
+        Class::Default
     }
 
     fn style(
@@ -18,7 +21,14 @@ impl Catalog for Theme {
         class: &<Self as Catalog>::Class<'_>,
         status: Status,
     ) -> Style {
-        class(self, status)
+        class.style(self, status)
+    }
+}
+
+style_class! {
+    /// The named styles of a slider.
+    (status: Status) -> Style {
+        Default => default,
     }
 }
 
@@ -27,7 +37,7 @@ pub fn styled(left: Color, right: Color, handle_radius: f32) -> Style {
         rail: Rail {
             backgrounds: (left.into(), right.into()),
             width: 8.0,
-            border: border::rounded(400),
+            border: border::rounded(Shapes::DEFAULT.full),
         },
         handle: Handle {
             shape: HandleShape::Circle {
@@ -40,24 +50,78 @@ pub fn styled(left: Color, right: Color, handle_radius: f32) -> Style {
     }
 }
 
+impl From<Status> for State {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Active => Self::Enabled,
+            Status::Hovered => Self::Hovered,
+            Status::Dragged => Self::Dragged,
+        }
+    }
+}
+
 pub fn default(theme: &Theme, status: Status) -> Style {
-    let surface = theme.colors().surface;
-    let primary = theme.colors().primary;
-    let secondary = theme.colors().secondary;
+    let colors = theme.colors();
+    let overrides = theme.component_override(Component::Slider, status.into());
 
-    match status {
-        Status::Active => {
-            styled(primary.color, secondary.secondary_container, 12.0)
-        }
-        Status::Hovered => styled(
-            mix(primary.color, surface.on_surface, HOVERED_LAYER_OPACITY),
-            secondary.secondary_container,
-            12.0,
-        ),
-        Status::Dragged => styled(
-            mix(primary.color, surface.on_surface, PRESSED_LAYER_OPACITY),
-            secondary.secondary_container,
-            11.0,
+    let style = match overrides.role {
+        Some(role) => styled_with(role)(theme, status),
+        None => colored(
+            theme,
+            colors.primary.color,
+            colors.secondary.secondary_container,
+            status,
         ),
+    };
+
+    Style {
+        rail: Rail {
+            border: overrides.border(style.rail.border),
+            ..style.rail
+        },
+        ..style
+    }
+}
+
+/// A slider colored with the given [`Role`].
+///
+/// The active track and the handle use the color of the [`Role`], and the
+/// inactive track its container color.
+pub fn styled_with(role: Role) -> impl Fn(&Theme, Status) -> Style {
+    move |theme, status| {
+        let group = theme.colors().role(role);
+
+        colored(theme, group.color, group.container, status)
+    }
+}
+
+fn colored(
+    theme: &Theme,
+    active: Color,
+    inactive: Color,
+    status: Status,
+) -> Style {
+    let surface = theme.colors().surface;
+
+    let (left, _) = theme.state_layer().compose(
+        active,
+        surface.on_surface,
+        surface.on_surface,
+        status.into(),
+    );
+    let handle_radius = if status == Status::Dragged {
+        11.0
+    } else {
+        12.0
+    };
+
+    let style = styled(left, inactive, handle_radius);
+
+    Style {
+        rail: Rail {
+            border: border::rounded(theme.shapes().full),
+            ..style.rail
+        },
+        ..style
     }
 }
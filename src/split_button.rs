@@ -0,0 +1,514 @@
+//! [Split buttons](https://m3.material.io/components/split-button/overview).
+//!
+//! A [`SplitButton`] joins a leading button with a trailing one that opens a
+//! menu of related actions. The trailing button turns fully round while the
+//! menu is open.
+//!
+//! The trailing button can be focused, and toggles the menu when
+//! <kbd>Enter</kbd> or <kbd>Space</kbd> is pressed.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced_material::split_button::SplitButton;
+//! use iced_material::{Theme, button};
+//! use iced_widget::text;
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Send,
+//!     Schedule(&'static str),
+//! }
+//!
+//! # fn view<'a>(theme: &Theme) -> iced_widget::core::Element<'a, Message, Theme, iced_widget::Renderer> {
+//! SplitButton::new(
+//!     theme,
+//!     button::filled,
+//!     text("Send"),
+//!     Message::Send,
+//!     ["Tomorrow", "Next week"],
+//!     Message::Schedule,
+//! )
+//! .into()
+//! # }
+//! ```
+use std::borrow::Borrow;
+use std::fmt;
+use std::marker::PhantomData;
+
+use iced_widget::Button;
+use iced_widget::button::{Status, Style};
+use iced_widget::core::alignment;
+use iced_widget::core::border::Radius;
+use iced_widget::core::keyboard::{self, key};
+use iced_widget::core::layout::{self, Layout, Limits};
+use iced_widget::core::text::{self, LineHeight, Shaping, Text, Wrapping};
+use iced_widget::core::widget::operation::Focusable;
+use iced_widget::core::widget::{Operation, Tree, tree};
+use iced_widget::core::{
+    Background, Clipboard, Color, Element, Event, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Vector, Widget, mouse, overlay, renderer,
+    touch,
+};
+use iced_widget::overlay::menu::{self, Menu};
+
+use crate::{Theme, sizing};
+
+/// The gap between the two buttons, in logical pixels.
+const GAP: f32 = 2.0;
+
+/// The width of the trailing button, in logical pixels.
+const TRAILING_WIDTH: f32 = 48.0;
+
+/// The horizontal padding of the leading button, in logical pixels.
+const PADDING_X: f32 = 16.0;
+
+/// A button with a trailing menu of related actions.
+pub struct SplitButton<'a, T, L, Message, Renderer = iced_widget::Renderer>
+where
+    T: ToString + Clone,
+    L: Borrow<[T]>,
+    Renderer: text::Renderer,
+{
+    leading: Button<'a, Message, Theme, Renderer>,
+    is_enabled: bool,
+    options: L,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    style: Box<dyn Fn(&Theme, Status) -> Style + 'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+    option: PhantomData<T>,
+}
+
+impl<'a, T, L, Message, Renderer> SplitButton<'a, T, L, Message, Renderer>
+where
+    T: ToString + Clone,
+    L: Borrow<[T]>,
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    /// Creates a [`SplitButton`] with the given button style, sized for the
+    /// [`Theme`].
+    ///
+    /// The leading button shows the content and produces `on_press`, while
+    /// the trailing one opens a menu of the given options.
+    pub fn new(
+        theme: &Theme,
+        style: impl Fn(&Theme, Status) -> Style + Copy + 'a,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_press: Message,
+        options: L,
+        on_select: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        let sizing = theme.sizing();
+        let label = theme.typography().label_large;
+
+        let leading = Button::new(content)
+            .on_press(on_press)
+            .padding(sizing::padding(
+                sizing.button_height(),
+                label.line_height,
+                PADDING_X,
+            ))
+            .style(move |theme, status| {
+                let shapes = theme.shapes();
+
+                shaped(
+                    style(theme, status),
+                    Radius {
+                        top_left: shapes.full,
+                        top_right: shapes.extra_small,
+                        bottom_right: shapes.extra_small,
+                        bottom_left: shapes.full,
+                    },
+                )
+            });
+
+        Self {
+            leading,
+            is_enabled: true,
+            options,
+            on_select: Box::new(on_select),
+            style: Box::new(style),
+            menu_class: <Theme as menu::Catalog>::default(),
+            option: PhantomData,
+        }
+    }
+
+    /// Sets the message produced by the leading button.
+    ///
+    /// The whole [`SplitButton`] is disabled when it is `None`.
+    pub fn on_press_maybe(self, on_press: Option<Message>) -> Self {
+        Self {
+            is_enabled: on_press.is_some(),
+            leading: self.leading.on_press_maybe(on_press),
+            ..self
+        }
+    }
+
+    /// Sets the class of the menu.
+    pub fn menu_class(
+        self,
+        menu_class: impl Into<<Theme as menu::Catalog>::Class<'a>>,
+    ) -> Self {
+        Self {
+            menu_class: menu_class.into(),
+            ..self
+        }
+    }
+}
+
+impl<T, L, Message, Renderer> fmt::Debug
+    for SplitButton<'_, T, L, Message, Renderer>
+where
+    T: ToString + Clone,
+    L: Borrow<[T]>,
+    Renderer: text::Renderer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitButton")
+            .field("is_enabled", &self.is_enabled)
+            .field("menu_class", &self.menu_class)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    menu: menu::State,
+    hovered_option: Option<usize>,
+    is_open: bool,
+    is_hovered: bool,
+    is_pressed: bool,
+    is_focused: bool,
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, T, L, Message, Renderer> Widget<Message, Theme, Renderer>
+    for SplitButton<'a, T, L, Message, Renderer>
+where
+    T: ToString + Clone + 'a,
+    L: Borrow<[T]>,
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.leading as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.leading as &dyn Widget<_, _, _>]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &Limits,
+    ) -> layout::Node {
+        let leading = self.leading.layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.loose(),
+        );
+        let size = leading.size();
+
+        let trailing =
+            layout::Node::new(Size::new(TRAILING_WIDTH, size.height))
+                .move_to(Point::new(size.width + GAP, 0.0));
+
+        layout::Node::with_children(
+            Size::new(size.width + GAP + TRAILING_WIDTH, size.height),
+            vec![leading, trailing],
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.leading.operate(
+            &mut tree.children[0],
+            layout.child(0),
+            renderer,
+            operation,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(None, layout.child(1).bounds(), state);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // A press while the menu is open only closes it.
+        if state.is_open
+            && matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                    | Event::Touch(touch::Event::FingerPressed { .. })
+            )
+        {
+            state.is_open = false;
+            shell.capture_event();
+            shell.request_redraw();
+            return;
+        }
+
+        self.leading.update(
+            &mut tree.children[0],
+            event,
+            layout.child(0),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.child(1).bounds();
+
+        let is_hovered = self.is_enabled && cursor.is_over(bounds);
+
+        if is_hovered != state.is_hovered {
+            state.is_hovered = is_hovered;
+            shell.request_redraw();
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = is_hovered;
+
+                if is_hovered {
+                    state.is_pressed = true;
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(
+                touch::Event::FingerLifted { .. }
+                | touch::Event::FingerLost { .. },
+            ) if state.is_pressed => {
+                state.is_pressed = false;
+
+                if is_hovered {
+                    state.is_open = true;
+                    state.hovered_option = None;
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Enter | key::Named::Space),
+                ..
+            }) if state.is_focused && self.is_enabled => {
+                state.is_open = !state.is_open;
+                state.hovered_option = None;
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.is_enabled && cursor.is_over(layout.child(1).bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            self.leading.mouse_interaction(
+                &tree.children[0],
+                layout.child(0),
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.leading.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.child(0),
+            cursor,
+            viewport,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.child(1).bounds();
+        let shapes = theme.shapes();
+
+        let status = if !self.is_enabled {
+            Status::Disabled
+        } else if state.is_pressed {
+            Status::Pressed
+        } else if state.is_hovered || state.is_focused {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = shaped(
+            (self.style)(theme, status),
+            if state.is_open {
+                Radius::from(shapes.full)
+            } else {
+                Radius {
+                    top_left: shapes.extra_small,
+                    top_right: shapes.full,
+                    bottom_right: shapes.full,
+                    bottom_left: shapes.extra_small,
+                }
+            },
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: style.shadow,
+                snap: style.snap,
+            },
+            style
+                .background
+                .unwrap_or(Background::Color(Color::TRANSPARENT)),
+        );
+
+        renderer.fill_text(
+            Text {
+                content: Renderer::ARROW_DOWN_ICON.to_string(),
+                bounds: bounds.size(),
+                size: Pixels(16.0),
+                line_height: LineHeight::default(),
+                font: Renderer::ICON_FONT,
+                align_x: text::Alignment::Center,
+                align_y: alignment::Vertical::Center,
+                shaping: Shaping::Basic,
+                wrapping: Wrapping::None,
+            },
+            bounds.center(),
+            style.text_color,
+            *viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+        let on_select = &self.on_select;
+
+        let menu = Menu::new(
+            &mut state.menu,
+            self.options.borrow(),
+            &mut state.hovered_option,
+            |option| {
+                state.is_open = false;
+
+                on_select(option)
+            },
+            None,
+            &self.menu_class,
+        )
+        .width(bounds.width)
+        .padding(Padding::from([8, 12]));
+
+        Some(menu.overlay(
+            layout.position() + translation,
+            *viewport,
+            bounds.height,
+            Length::Shrink,
+        ))
+    }
+}
+
+impl<'a, T, L, Message, Renderer> From<SplitButton<'a, T, L, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: ToString + Clone + 'a,
+    L: Borrow<[T]> + 'a,
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(button: SplitButton<'a, T, L, Message, Renderer>) -> Self {
+        Self::new(button)
+    }
+}
+
+fn shaped(style: Style, radius: Radius) -> Style {
+    Style {
+        border: style.border.rounded(radius),
+        ..style
+    }
+}
@@ -1,15 +1,27 @@
 use iced_widget::svg::{Catalog, Status, Style, StyleFn};
 
 use super::Theme;
+use crate::class::style_class;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(|_theme, _status| Style::default())
+        Class::Original
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.style(self, status)
     }
 }
+
+style_class! {
+    /// The named styles of an SVG.
+    (status: Status) -> Style {
+        Original => original,
+    }
+}
+
+pub fn original(_theme: &Theme, _status: Status) -> Style {
+    Style::default()
+}
@@ -2,16 +2,35 @@
 use iced_widget::text::{Catalog, Style, StyleFn};
 
 use crate::Theme;
+use crate::class::style_class;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(none)
+        Class::None
     }
 
     fn style(&self, class: &Self::Class<'_>) -> Style {
-        class(self)
+        class.style(self)
+    }
+}
+
+style_class! {
+    /// The named styles of a text.
+    () -> Style {
+        None => none,
+        Primary => primary,
+        PrimaryContainer => primary_container,
+        Secondary => secondary,
+        SecondaryContainer => secondary_container,
+        Tertiary => tertiary,
+        TertiaryContainer => tertiary_container,
+        Error => error,
+        ErrorContainer => error_container,
+        Surface => surface,
+        SurfaceVariant => surface_variant,
+        InverseSurface => inverse_surface,
     }
 }
 
@@ -1,35 +1,87 @@
-use iced_widget::core::{Background, Border, Color, border};
+use iced_widget::core::{Background, Border, Color};
 use iced_widget::text_editor::{Catalog, Status, Style, StyleFn};
 
-use super::Theme;
-use crate::utils::{disabled_container, disabled_text};
+use super::{Role, Theme};
+use crate::class::style_class;
+use crate::overrides::Component;
+use crate::utils::{State, disabled_container, disabled_text};
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        Class::Default
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.style(self, status)
+    }
+}
+
+style_class! {
+    /// The named styles of a text editor.
+    (status: Status) -> Style {
+        Default => default,
+        Error => error,
+    }
+}
+
+impl From<Status> for State {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Active => Self::Enabled,
+            Status::Hovered => Self::Hovered,
+            Status::Focused { .. } => Self::Focused,
+            Status::Disabled => Self::Disabled,
+        }
     }
 }
 
 pub fn default(theme: &Theme, status: Status) -> Style {
+    let overrides =
+        theme.component_override(Component::TextEditor, status.into());
+    let role = overrides.role_or(Role::Primary);
+    let style = colored(theme, theme.colors().role(role).color, status);
+
+    Style {
+        border: overrides.border(style.border),
+        ..style
+    }
+}
+
+pub fn error(theme: &Theme, status: Status) -> Style {
+    let error = theme.colors().error;
+    let style = colored(theme, error.color, status);
+
+    let outline = match status {
+        Status::Disabled => return style,
+        Status::Hovered => error.on_error_container,
+        Status::Active | Status::Focused { .. } => error.color,
+    };
+
+    Style {
+        border: Border {
+            color: outline,
+            ..style.border
+        },
+        placeholder: error.color,
+        ..style
+    }
+}
+
+fn colored(theme: &Theme, color: Color, status: Status) -> Style {
     let surface = theme.colors().surface;
-    let primary = theme.colors().primary;
 
     let active = Style {
         background: Background::Color(surface.surface_container.highest),
         border: Border {
             color: theme.colors().outline.color,
             width: 1.0,
-            radius: 4.into(),
+            radius: theme.shapes().extra_small.into(),
         },
         placeholder: surface.on_surface_variant,
         value: surface.on_surface,
-        selection: disabled_text(primary.color),
+        selection: disabled_text(color),
     };
 
     match status {
@@ -43,11 +95,11 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         },
         Status::Focused { .. } => Style {
             border: Border {
-                color: primary.color,
+                color,
                 width: 2.0,
                 ..active.border
             },
-            placeholder: primary.color,
+            placeholder: color,
             ..active
         },
         Status::Disabled => Style {
@@ -55,7 +107,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             border: Border {
                 color: disabled_container(surface.on_surface),
                 width: 1.0,
-                radius: border::radius(4),
+                radius: theme.shapes().extra_small.into(),
             },
             placeholder: disabled_text(surface.on_surface),
             value: disabled_text(surface.on_surface),
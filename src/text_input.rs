@@ -1,36 +1,91 @@
 use iced_widget::core::{Background, Border, Color};
 use iced_widget::text_input::{Catalog, Status, Style, StyleFn};
 
-use super::Theme;
-use crate::utils::{disabled_container, disabled_text};
+use super::{Role, Theme};
+use crate::class::style_class;
+use crate::overrides::Component;
+use crate::shape::Shape;
+use crate::utils::{State, disabled_container, disabled_text};
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        Class::Default
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.style(self, status)
+    }
+}
+
+style_class! {
+    /// The named styles of a text input.
+    (status: Status) -> Style {
+        Default => default,
+        Error => error,
+    }
+}
+
+impl From<Status> for State {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Active => Self::Enabled,
+            Status::Hovered => Self::Hovered,
+            Status::Focused { .. } => Self::Focused,
+            Status::Disabled => Self::Disabled,
+        }
     }
 }
 
 pub fn default(theme: &Theme, status: Status) -> Style {
+    let overrides =
+        theme.component_override(Component::TextInput, status.into());
+    let role = overrides.role_or(Role::Primary);
+    let style = colored(theme, theme.colors().role(role).color, status);
+
+    Style {
+        border: overrides.border(style.border),
+        ..style
+    }
+}
+
+pub fn styled_with(role: Role) -> impl Fn(&Theme, Status) -> Style {
+    move |theme, status| colored(theme, theme.colors().role(role).color, status)
+}
+
+pub fn error(theme: &Theme, status: Status) -> Style {
+    let error = theme.colors().error;
+    let style = colored(theme, error.color, status);
+
+    let outline = match status {
+        Status::Disabled => return style,
+        Status::Hovered => error.on_error_container,
+        Status::Active | Status::Focused { .. } => error.color,
+    };
+
+    Style {
+        border: style.border.color(outline),
+        icon: error.color,
+        placeholder: error.color,
+        ..style
+    }
+}
+
+fn colored(theme: &Theme, color: Color, status: Status) -> Style {
     let surface = theme.colors().surface;
-    let primary = theme.colors().primary;
 
     let active = Style {
         background: Background::Color(surface.surface_container.highest),
         border: Border {
             color: theme.colors().outline.color,
             width: 1.0,
-            radius: 4.into(),
+            radius: theme.shapes().extra_small.into(),
         },
         icon: surface.on_surface_variant,
         placeholder: surface.on_surface_variant,
         value: surface.on_surface,
-        selection: disabled_text(primary.color),
+        selection: disabled_text(color),
     };
 
     match status {
@@ -52,12 +107,101 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         },
         Status::Focused { .. } => Style {
             border: Border {
-                color: primary.color,
+                color,
                 width: 2.0,
                 ..active.border
             },
-            placeholder: primary.color,
+            placeholder: color,
             ..active
         },
     }
 }
+
+/// A builder for text input styles.
+///
+/// # Example
+///
+/// ```no_run
+/// use iced::widget::{TextInput, text_input};
+/// use iced_material::shape::Shape;
+/// use iced_material::text_input::TextInputStyle;
+/// use iced_material::{Role, Theme};
+///
+/// let style = TextInputStyle::outlined()
+///     .role(Role::Tertiary)
+///     .shape(Shape::Small);
+///
+/// let name: TextInput<'_, (), Theme> =
+///     text_input("Name", "").style(style.into_fn());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextInputStyle {
+    filled: bool,
+    role: Role,
+    shape: Shape,
+}
+
+impl TextInputStyle {
+    /// A text input with a filled background.
+    pub const fn filled() -> Self {
+        Self {
+            filled: true,
+            role: Role::Primary,
+            shape: Shape::ExtraSmall,
+        }
+    }
+
+    /// A text input with a transparent background.
+    pub const fn outlined() -> Self {
+        Self {
+            filled: false,
+            ..Self::filled()
+        }
+    }
+
+    /// Sets the color [`Role`] used when the text input is focused.
+    pub const fn role(self, role: Role) -> Self {
+        Self { role, ..self }
+    }
+
+    /// Sets the [`Shape`] of the text input.
+    pub const fn shape(self, shape: Shape) -> Self {
+        Self { shape, ..self }
+    }
+
+    /// Turns the style into a function that can be passed to the `style`
+    /// method of a [`TextInput`].
+    ///
+    /// [`TextInput`]: iced_widget::TextInput
+    pub fn into_fn(self) -> impl Fn(&Theme, Status) -> Style + Copy {
+        move |theme, status| self.style(theme, status)
+    }
+
+    /// Computes the [`Style`] of the text input.
+    pub fn style(&self, theme: &Theme, status: Status) -> Style {
+        let style =
+            colored(theme, theme.colors().role(self.role).color, status);
+
+        Style {
+            background: if self.filled {
+                style.background
+            } else {
+                Color::TRANSPARENT.into()
+            },
+            border: style.border.rounded(theme.shapes().radius(self.shape)),
+            ..style
+        }
+    }
+}
+
+impl From<TextInputStyle> for StyleFn<'_, Theme> {
+    fn from(style: TextInputStyle) -> Self {
+        Box::new(move |theme, status| style.style(theme, status))
+    }
+}
+
+impl From<TextInputStyle> for Class<'_> {
+    fn from(style: TextInputStyle) -> Self {
+        Self::Custom(style.into())
+    }
+}
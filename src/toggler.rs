@@ -1,20 +1,37 @@
-use iced_widget::core::Color;
+use iced_widget::core::{Border, Color};
 use iced_widget::toggler::{Catalog, Status, Style, StyleFn};
 
-use super::Theme;
-use crate::utils::{
-    HOVERED_LAYER_OPACITY, disabled_container, disabled_text, mix,
-};
+use super::{ColorGroup, Role, Theme};
+use crate::class::style_class;
+use crate::overrides::Component;
+use crate::utils::State;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = Class<'a>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        Class::Default
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.style(self, status)
+    }
+}
+
+style_class! {
+    /// The named styles of a toggler.
+    (status: Status) -> Style {
+        Default => default,
+    }
+}
+
+impl From<Status> for State {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Active { .. } => Self::Enabled,
+            Status::Hovered { .. } => Self::Hovered,
+            Status::Disabled => Self::Disabled,
+        }
     }
 }
 
@@ -34,40 +51,143 @@ pub fn styled(
 }
 
 pub fn default(theme: &Theme, status: Status) -> Style {
+    let overrides = theme.component_override(Component::Toggler, status.into());
+    let group = theme.colors().role(overrides.role_or(Role::Primary));
+    let style = colored(theme, group, status);
+
+    let border = overrides.border(Border {
+        color: style.background_border_color,
+        width: style.background_border_width,
+        ..Border::default()
+    });
+
+    Style {
+        background_border_color: border.color,
+        background_border_width: border.width,
+        ..style
+    }
+}
+
+pub fn styled_with(role: Role) -> impl Fn(&Theme, Status) -> Style {
+    move |theme, status| colored(theme, theme.colors().role(role), status)
+}
+
+fn colored(theme: &Theme, group: ColorGroup, status: Status) -> Style {
     let surface = theme.colors().surface;
-    let primary = theme.colors().primary;
+    let outline = theme.colors().outline.color;
+    let state_layer = theme.state_layer();
 
     match status {
-        Status::Active { is_toggled } => {
+        Status::Active { is_toggled } | Status::Hovered { is_toggled } => {
+            let state = State::from(status);
+
             if is_toggled {
-                styled(primary.color, primary.on_primary, None)
+                let foreground = if state == State::Hovered {
+                    group.container
+                } else {
+                    group.on_color
+                };
+
+                styled(group.color, foreground, None)
             } else {
-                styled(
+                let (background, _) = state_layer.compose(
                     surface.surface_container.highest,
-                    theme.colors().outline.color,
-                    Some(theme.colors().outline.color),
-                )
-            }
-        }
-        Status::Hovered { is_toggled } => {
-            if is_toggled {
-                styled(primary.color, primary.primary_container, None)
-            } else {
-                styled(
-                    mix(
-                        surface.surface_container.highest,
-                        surface.on_surface,
-                        HOVERED_LAYER_OPACITY,
-                    ),
-                    surface.on_surface_variant,
-                    Some(theme.colors().outline.color),
-                )
+                    surface.on_surface,
+                    surface.on_surface,
+                    state,
+                );
+                let foreground = if state == State::Hovered {
+                    surface.on_surface_variant
+                } else {
+                    outline
+                };
+
+                styled(background, foreground, Some(outline))
             }
         }
         Status::Disabled => styled(
-            disabled_container(surface.surface_container.highest),
-            disabled_text(surface.on_surface),
-            Some(disabled_text(surface.on_surface)),
+            state_layer.disabled_container(surface.surface_container.highest),
+            state_layer.disabled_content(surface.on_surface),
+            Some(state_layer.disabled_content(surface.on_surface)),
         ),
     }
 }
+
+/// A builder for toggler styles.
+///
+/// # Example
+///
+/// ```no_run
+/// use iced::widget::{Toggler, toggler};
+/// use iced_material::toggler::TogglerStyle;
+/// use iced_material::{Role, Theme};
+///
+/// let style = TogglerStyle::new().role(Role::Tertiary).outline(false);
+///
+/// let wifi: Toggler<'_, (), Theme> = toggler(true).style(style.into_fn());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TogglerStyle {
+    role: Role,
+    outline: bool,
+}
+
+impl TogglerStyle {
+    pub const fn new() -> Self {
+        Self {
+            role: Role::Primary,
+            outline: true,
+        }
+    }
+
+    /// Sets the color [`Role`] of the toggler when toggled.
+    pub const fn role(self, role: Role) -> Self {
+        Self { role, ..self }
+    }
+
+    /// Sets whether the track is outlined when the toggler is not toggled.
+    pub const fn outline(self, outline: bool) -> Self {
+        Self { outline, ..self }
+    }
+
+    /// Turns the style into a function that can be passed to the `style`
+    /// method of a [`Toggler`].
+    ///
+    /// [`Toggler`]: iced_widget::Toggler
+    pub fn into_fn(self) -> impl Fn(&Theme, Status) -> Style + Copy {
+        move |theme, status| self.style(theme, status)
+    }
+
+    /// Computes the [`Style`] of the toggler.
+    pub fn style(&self, theme: &Theme, status: Status) -> Style {
+        let style = colored(theme, theme.colors().role(self.role), status);
+
+        if self.outline {
+            style
+        } else {
+            Style {
+                background_border_width: 0.0,
+                background_border_color: Color::TRANSPARENT,
+                ..style
+            }
+        }
+    }
+}
+
+impl Default for TogglerStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<TogglerStyle> for StyleFn<'_, Theme> {
+    fn from(style: TogglerStyle) -> Self {
+        Box::new(move |theme, status| style.style(theme, status))
+    }
+}
+
+impl From<TogglerStyle> for Class<'_> {
+    fn from(style: TogglerStyle) -> Self {
+        Self::Custom(style.into())
+    }
+}
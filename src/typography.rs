@@ -0,0 +1,204 @@
+//! The Material 3 [type scale](https://m3.material.io/styles/typography/type-scale-tokens).
+use iced_widget::Text;
+use iced_widget::core::Pixels;
+use iced_widget::core::font::{self, Font};
+use iced_widget::core::text::{self, IntoFragment, LineHeight};
+
+use crate::Theme;
+
+/// The size, line height, weight and letter spacing of a typographic role.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TypeStyle {
+    /// The font size, in logical pixels.
+    pub size: f32,
+    /// The line height, in logical pixels.
+    pub line_height: f32,
+    /// The font weight.
+    #[cfg_attr(feature = "serde", serde(with = "weight_serde"))]
+    pub weight: font::Weight,
+    /// The letter spacing (tracking), in logical pixels.
+    ///
+    /// `iced` does not support letter spacing yet, so this is currently
+    /// informational only.
+    pub letter_spacing: f32,
+}
+
+impl TypeStyle {
+    const fn new(
+        size: f32,
+        line_height: f32,
+        weight: font::Weight,
+        letter_spacing: f32,
+    ) -> Self {
+        Self {
+            size,
+            line_height,
+            weight,
+            letter_spacing,
+        }
+    }
+
+    /// The [`Font`] of the [`TypeStyle`].
+    ///
+    /// It uses the Roboto family when the `roboto` feature is enabled.
+    pub fn font(&self) -> Font {
+        #[cfg(feature = "roboto")]
+        {
+            crate::roboto::font(self.weight)
+        }
+
+        #[cfg(not(feature = "roboto"))]
+        Font {
+            weight: self.weight,
+            ..Font::DEFAULT
+        }
+    }
+
+    /// Applies the [`TypeStyle`] to the given [`Text`].
+    pub fn apply<'a, Renderer>(
+        &self,
+        text: Text<'a, Theme, Renderer>,
+    ) -> Text<'a, Theme, Renderer>
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        text.size(self.size)
+            .line_height(LineHeight::Absolute(Pixels(self.line_height)))
+            .font(self.font())
+    }
+}
+
+/// A [`Theme`]'s type scale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Typography {
+    pub display_large: TypeStyle,
+    pub display_medium: TypeStyle,
+    pub display_small: TypeStyle,
+    pub headline_large: TypeStyle,
+    pub headline_medium: TypeStyle,
+    pub headline_small: TypeStyle,
+    pub title_large: TypeStyle,
+    pub title_medium: TypeStyle,
+    pub title_small: TypeStyle,
+    pub body_large: TypeStyle,
+    pub body_medium: TypeStyle,
+    pub body_small: TypeStyle,
+    pub label_large: TypeStyle,
+    pub label_medium: TypeStyle,
+    pub label_small: TypeStyle,
+}
+
+impl Typography {
+    /// The default Material 3 type scale.
+    pub const DEFAULT: Self = {
+        use font::Weight::{Medium, Normal};
+
+        Self {
+            display_large: TypeStyle::new(57.0, 64.0, Normal, -0.25),
+            display_medium: TypeStyle::new(45.0, 52.0, Normal, 0.0),
+            display_small: TypeStyle::new(36.0, 44.0, Normal, 0.0),
+            headline_large: TypeStyle::new(32.0, 40.0, Normal, 0.0),
+            headline_medium: TypeStyle::new(28.0, 36.0, Normal, 0.0),
+            headline_small: TypeStyle::new(24.0, 32.0, Normal, 0.0),
+            title_large: TypeStyle::new(22.0, 28.0, Normal, 0.0),
+            title_medium: TypeStyle::new(16.0, 24.0, Medium, 0.15),
+            title_small: TypeStyle::new(14.0, 20.0, Medium, 0.1),
+            body_large: TypeStyle::new(16.0, 24.0, Normal, 0.5),
+            body_medium: TypeStyle::new(14.0, 20.0, Normal, 0.25),
+            body_small: TypeStyle::new(12.0, 16.0, Normal, 0.4),
+            label_large: TypeStyle::new(14.0, 20.0, Medium, 0.1),
+            label_medium: TypeStyle::new(12.0, 16.0, Medium, 0.5),
+            label_small: TypeStyle::new(11.0, 16.0, Medium, 0.5),
+        }
+    };
+}
+
+impl Default for Typography {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+macro_rules! text_helpers {
+    ($($role:ident: $name:literal),* $(,)?) => {
+        $(
+            #[doc = concat!("Creates a [`Text`] using the ", $name, " role of the [`Theme`]'s [`Typography`].")]
+            pub fn $role<'a, Renderer>(
+                theme: &Theme,
+                content: impl IntoFragment<'a>,
+            ) -> Text<'a, Theme, Renderer>
+            where
+                Renderer: text::Renderer<Font = Font>,
+            {
+                theme.typography().$role.apply(Text::new(content))
+            }
+        )*
+    };
+}
+
+text_helpers! {
+    display_large: "display large",
+    display_medium: "display medium",
+    display_small: "display small",
+    headline_large: "headline large",
+    headline_medium: "headline medium",
+    headline_small: "headline small",
+    title_large: "title large",
+    title_medium: "title medium",
+    title_small: "title small",
+    body_large: "body large",
+    body_medium: "body medium",
+    body_small: "body small",
+    label_large: "label large",
+    label_medium: "label medium",
+    label_small: "label small",
+}
+
+#[cfg(feature = "serde")]
+mod weight_serde {
+    use iced_widget::core::font::Weight;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Weight, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match u16::deserialize(deserializer)? {
+            ..=150 => Weight::Thin,
+            151..=250 => Weight::ExtraLight,
+            251..=350 => Weight::Light,
+            351..=450 => Weight::Normal,
+            451..=550 => Weight::Medium,
+            551..=650 => Weight::Semibold,
+            651..=750 => Weight::Bold,
+            751..=850 => Weight::ExtraBold,
+            851.. => Weight::Black,
+        })
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn serialize<S>(
+        weight: &Weight,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let weight: u16 = match weight {
+            Weight::Thin => 100,
+            Weight::ExtraLight => 200,
+            Weight::Light => 300,
+            Weight::Normal => 400,
+            Weight::Medium => 500,
+            Weight::Semibold => 600,
+            Weight::Bold => 700,
+            Weight::ExtraBold => 800,
+            Weight::Black => 900,
+        };
+
+        weight.serialize(serializer)
+    }
+}
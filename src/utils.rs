@@ -3,11 +3,115 @@ use iced_widget::core::{Color, Shadow, Vector};
 const COLOR_ERROR_MARGIN: f32 = 0.0001;
 
 pub const HOVERED_LAYER_OPACITY: f32 = 0.08;
+pub const FOCUSED_LAYER_OPACITY: f32 = 0.1;
 pub const PRESSED_LAYER_OPACITY: f32 = 0.1;
+pub const DRAGGED_LAYER_OPACITY: f32 = 0.16;
 
 pub const DISABLED_TEXT_OPACITY: f32 = 0.38;
 pub const DISABLED_CONTAINER_OPACITY: f32 = 0.12;
 
+/// The interaction state of a component, used to pick its [`StateLayer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum State {
+    Enabled,
+    Hovered,
+    Focused,
+    Pressed,
+    Dragged,
+    Disabled,
+}
+
+/// The opacities of the [state layers] applied on top of interactive
+/// components.
+///
+/// [state layers]: https://m3.material.io/foundations/interaction/states/state-layers
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct StateLayer {
+    pub hover: f32,
+    pub focus: f32,
+    pub press: f32,
+    pub drag: f32,
+    /// The opacity of the container of a disabled component.
+    pub disabled_container: f32,
+    /// The opacity of the content of a disabled component.
+    pub disabled_content: f32,
+}
+
+impl StateLayer {
+    /// The default Material 3 state layer opacities.
+    pub const DEFAULT: Self = Self {
+        hover: HOVERED_LAYER_OPACITY,
+        focus: FOCUSED_LAYER_OPACITY,
+        press: PRESSED_LAYER_OPACITY,
+        drag: DRAGGED_LAYER_OPACITY,
+        disabled_container: DISABLED_CONTAINER_OPACITY,
+        disabled_content: DISABLED_TEXT_OPACITY,
+    };
+
+    /// Returns the opacity of the layer drawn in the given [`State`].
+    pub fn opacity(&self, state: State) -> f32 {
+        match state {
+            State::Enabled | State::Disabled => 0.0,
+            State::Hovered => self.hover,
+            State::Focused => self.focus,
+            State::Pressed => self.press,
+            State::Dragged => self.drag,
+        }
+    }
+
+    /// Composes the container and content colors of a component in the
+    /// given [`State`].
+    ///
+    /// The state layer uses the `content` color on top of the `container`.
+    /// A disabled component uses the `disabled` color for both, with the
+    /// disabled opacities applied, while a transparent container stays
+    /// transparent.
+    pub fn compose(
+        &self,
+        container: Color,
+        content: Color,
+        disabled: Color,
+        state: State,
+    ) -> (Color, Color) {
+        match state {
+            State::Disabled => (
+                if container.a == 0.0 {
+                    container
+                } else {
+                    self.disabled_container(disabled)
+                },
+                self.disabled_content(disabled),
+            ),
+            state => (mix(container, content, self.opacity(state)), content),
+        }
+    }
+
+    /// Applies the disabled container opacity to the given [`Color`].
+    pub fn disabled_container(&self, color: Color) -> Color {
+        Color {
+            a: self.disabled_container,
+            ..color
+        }
+    }
+
+    /// Applies the disabled content opacity to the given [`Color`].
+    pub fn disabled_content(&self, color: Color) -> Color {
+        Color {
+            a: self.disabled_content,
+            ..color
+        }
+    }
+}
+
+impl Default for StateLayer {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 pub fn elevation(elevation_level: u8) -> f32 {
     (match elevation_level {
         0 => 0.0,